@@ -29,6 +29,10 @@ fn main() {
             }
             ParseResult::Date(date) => println!("Date: {date}\n"),
             ParseResult::Time(time) => println!("Time: {time}\n"),
+            ParseResult::DateRange(start, end) => println!("From: {start}\nTo: {end}\n"),
+            ParseResult::YearMonth(year, month) => println!("Month: {year:04}-{:02}\n", month.number_from_month()),
+            ParseResult::Year(year) => println!("Year: {year:04}\n"),
+            result => println!("{result}\n"),
         };
     }
 }
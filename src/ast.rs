@@ -1,3 +1,5 @@
+use std::{fmt::Display, ops::Range};
+
 use chrono::Month;
 use pest_consume::{match_nodes, Error, Parser as ConsumeParser};
 use pest_derive::Parser;
@@ -58,9 +60,8 @@ fn parse_spelled_number(s: &str) -> Option<u32> {
 
 fn parse_compound_ordinal_text(s: &str) -> Option<u32> {
     // Handle compound ordinals like "three hundredth", "two hundred fiftieth"
-    if s.ends_with("hundredth") {
+    if let Some(prefix) = s.strip_suffix("hundredth") {
         // "three hundredth" -> 300
-        let prefix = &s[..s.len() - 9]; // Remove "hundredth"
         if let Some(hundreds) = parse_spelled_number(prefix) {
             return Some(hundreds * 100);
         }
@@ -123,7 +124,7 @@ fn parse_simple_ordinal(s: &str) -> Option<u32> {
         "ninetieth" => Some(90),
         _ => {
             // Handle compound forms like "twenty first", "thirty second"
-            parse_compound_ordinal(&s)
+            parse_compound_ordinal(s)
         }
     }
 }
@@ -180,12 +181,27 @@ fn parse_compound_ordinal(s: &str) -> Option<u32> {
 }
 
 
-pub fn build_ast_from(str: &str) -> Result<HumanTime, ParseError> {
-    let result = DateTimeParser::parse(Rule::HumanTime, &str)
+/// With `four_digit_times` a bare "1530" is a time instead of a year.
+pub fn build_ast_from(str: &str, four_digit_times: bool) -> Result<HumanTime, ParseError> {
+    build_ast_with_span(str, four_digit_times).map(|(human_time, _)| human_time)
+}
+
+/// Like `build_ast_from`, but also returns the byte range of `str` that was parsed.
+pub fn build_ast_with_span(str: &str, four_digit_times: bool) -> Result<(HumanTime, Range<usize>), ParseError> {
+    let rule = if four_digit_times { Rule::FourDigitHumanTime } else { Rule::HumanTime };
+    let result = DateTimeParser::parse(rule, str)
         .and_then(|result| result.single())
         .map_err(|_| ParseError::InvalidFormat)?;
+    let span = result.as_span();
+    let span = span.start()..span.end();
 
-    DateTimeParser::HumanTime(result)
+    let human_time = if four_digit_times {
+        DateTimeParser::FourDigitHumanTime(result)
+    } else {
+        DateTimeParser::HumanTime(result)
+    };
+    human_time
+        .map(|human_time| (human_time, span))
         .map_err(|_| ParseError::InternalError(InternalError::FailedToBuildAst))
 }
 
@@ -202,6 +218,8 @@ impl DateTimeParser {
             [Time(t)] => HumanTime::Time(t),
             [In(i)] => HumanTime::In(i),
             [Ago(a)] => HumanTime::Ago(a),
+            [PeriodBoundary((b, r))] => HumanTime::PeriodBoundary(b, r),
+            [BusinessHoursBoundary((b, d))] => HumanTime::BusinessHoursBoundary(b, d),
             [Now(_)] => HumanTime::Now,
         ))
     }
@@ -219,6 +237,39 @@ impl DateTimeParser {
         ))
     }
 
+    fn IsoWeekDate(input: Node) -> ParserResult<IsoWeekDate> {
+        Ok(match_nodes!(input.into_children();
+            [Num(year), Num(week)] => IsoWeekDate{year, week, weekday: None},
+            [Num(year), Num(week), Num(weekday)] => IsoWeekDate{year, week, weekday: Some(weekday)},
+        ))
+    }
+
+    fn WeekNumber(input: Node) -> ParserResult<(u32, Option<YearSpec>)> {
+        Ok(match_nodes!(input.into_children();
+            [Num(week)] => (week, None),
+            [Num(week), YearSpec(year)] => (week, Some(year)),
+            [Num(week), Num(year)] => (week, Some(YearSpec::Absolute(year))),
+        ))
+    }
+
+    fn IsoOrdinalDate(input: Node) -> ParserResult<(u32, YearSpec)> {
+        Ok(match_nodes!(input.into_children();
+            [Num(year), IsoDayOfYear(day)] => (day, YearSpec::Absolute(year)),
+        ))
+    }
+
+    fn IsoDayOfYear(input: Node) -> ParserResult<u32> {
+        input.as_str().parse::<u32>().map_err(|e| input.error(e))
+    }
+
+    fn DayNumber(input: Node) -> ParserResult<(u32, Option<YearSpec>)> {
+        Ok(match_nodes!(input.into_children();
+            [Num(day)] => (day, None),
+            [Num(day), YearSpec(year)] => (day, Some(year)),
+            [Num(day), Num(year)] => (day, Some(YearSpec::Absolute(year))),
+        ))
+    }
+
     fn Date(input: Node) -> ParserResult<Date> {
         Ok(match_nodes!(input.into_children();
             [Today(_)] => Date::Today,
@@ -226,8 +277,19 @@ impl DateTimeParser {
             [Overmorrow(_)] => Date::Overmorrow,
             [Yesterday(_)] => Date::Yesterday,
             [IsoDate(iso)] => Date::IsoDate(iso),
-            [Num(d), Month_Name(m), Num(y)] => Date::DayMonthYear(d, m, y),
-            [Num(d), Month_Name(m)] => Date::DayMonth(d, m),
+            [IsoWeekDate(iso)] => Date::IsoWeekDate(iso),
+            [WeekNumber((week, year))] => Date::WeekNumber(week, year),
+            [IsoOrdinalDate((day, year))] => Date::DayOfYear(day, Some(year)),
+            [DayNumber((day, year))] => Date::DayOfYear(day, year),
+            [Num(d), Month_Name(m), YearSpec(y)] => Date::DayMonth(d, m, Some(y)),
+            [Num(d), Month_Name(m), Num(y)] => Date::DayMonth(d, m, Some(YearSpec::Absolute(y))),
+            [Num(d), Month_Name(m)] => Date::DayMonth(d, m, None),
+            [Month_Name(m), Num(d), YearSpec(y)] => Date::DayMonth(d, m, Some(y)),
+            [Month_Name(m), Num(d), YearNumber(y)] => Date::DayMonth(d, m, Some(YearSpec::Absolute(y))),
+            [Month_Name(m), Num(d), TwoDigitYearNumber(y)] => Date::DayMonth(d, m, Some(YearSpec::Absolute(y))),
+            [Month_Name(m), Num(d)] => Date::DayMonth(d, m, None),
+            [MonthOfYear(spec)] => Date::MonthOfYear(spec),
+            [YearNumber(year)] => Date::Year(year),
             [MonthDurationFromNow((m, q))] => Date::MonthDurationFromNow(m, Duration(vec![q])),
             [MonthDurationAgo((m, q))] => Date::MonthDurationAgo(m, Duration(vec![q])),
             [RelativeSpecifier(r), Week(_), Weekday(wd)] => Date::RelativeWeekWeekday(r, wd),
@@ -235,9 +297,72 @@ impl DateTimeParser {
             [RelativeSpecifier(r), Weekday(wd)] => Date::RelativeWeekday(r, wd),
             [Weekday(wd)] => Date::UpcomingWeekday(wd),
             [OrdinalTimeUnitOf((ordinal, time_unit, datetime_ref))] => Date::OrdinalTimeUnitOf(ordinal, time_unit, datetime_ref),
+            [PartOfPeriod((part, datetime_ref))] => Date::PartOfPeriod(part, datetime_ref),
+            [QuarterSpec(spec)] => Date::QuarterSpec(spec),
+            [Duration(d), Relation(relation), Date(date)] => Date::DurationRelativeTo(d, relation, Box::new(date)),
+            [Weekday(wd), Relation(relation), Date(date)] => Date::WeekdayRelativeTo(wd, relation, Box::new(date)),
+            [RelativeSpecifier(r), Holiday(name)] => Date::RelativeHoliday(r, name),
+            [Holiday(name), YearSpec(y)] => Date::Holiday(name, Some(y)),
+            [Holiday(name), YearNumber(y)] => Date::Holiday(name, Some(YearSpec::Absolute(y))),
+            [Holiday(name)] => Date::Holiday(name, None),
+            [BareDayOfMonth(day)] => Date::DayOfMonth(day),
+            [DayOfMonthSpec((ordinal, datetime_ref))] => Date::OrdinalTimeUnitOf(ordinal, TimeUnit::Day, datetime_ref),
+            [WeekendSpec(spec)] => Date::Weekend(spec),
+            [SeasonSpec(spec)] => Date::Season(spec),
+            [EveryWeekday(_)] => Date::EveryWeekday,
+        ))
+    }
+
+    fn DayOfMonthSpec(input: Node) -> ParserResult<(Ordinal, DateTimeReference)> {
+        Ok(match_nodes!(input.into_children();
+            [Ordinal(ordinal), MonthSpec(month_spec)] => (ordinal, DateTimeReference::MonthYear(month_spec, None)),
+            [Ordinal(ordinal), MonthSpec(month_spec), YearSpec(year_spec)] => {
+                (ordinal, DateTimeReference::MonthYear(month_spec, Some(year_spec)))
+            },
+            [Ordinal(ordinal), MonthSpec(month_spec), YearNumber(year)] => {
+                (ordinal, DateTimeReference::MonthYear(month_spec, Some(YearSpec::Absolute(year))))
+            },
+        ))
+    }
+
+    fn BareDayOfMonth(input: Node) -> ParserResult<u32> {
+        match_nodes!(input.children();
+            [Ordinal(ordinal)] => match ordinal {
+                Ordinal::First => Ok(1),
+                Ordinal::Nth(day) => Ok(day),
+                Ordinal::Last => Err(input.error("\"The last\" is not a day of the month")),
+            },
+        )
+    }
+
+    fn WeekendSpec(input: Node) -> ParserResult<WeekendSpec> {
+        Ok(match_nodes!(input.into_children();
+            [Next(_)] => WeekendSpec::AfterNext,
+            [RelativeSpecifier(relative)] => WeekendSpec::Relative(relative),
+            [] => WeekendSpec::Relative(RelativeSpecifier::This),
         ))
     }
 
+    fn EveryWeekday(input: Node) -> ParserResult<EveryWeekday> {
+        Ok(EveryWeekday {})
+    }
+
+    fn Relation(input: Node) -> ParserResult<Relation> {
+        if let Some(rule) = input.children().next() {
+            Ok(match rule.as_rule() {
+                Rule::Before => Relation::Before,
+                Rule::After => Relation::After,
+                _ => unreachable!(),
+            })
+        } else {
+            Err(input.error("Unreachable"))
+        }
+    }
+
+    fn Holiday(input: Node) -> ParserResult<String> {
+        Ok(input.as_str().split_whitespace().collect::<Vec<_>>().join(" "))
+    }
+
     fn Week(input: Node) -> ParserResult<Week> {
         Ok(Week {})
     }
@@ -273,6 +398,36 @@ impl DateTimeParser {
         Ok(match_nodes!(input.into_children();
             [Num(h), Num(m)] => Time::HourMinute(h, m),
             [Num(h), Num(m), Num(s)] => Time::HourMinuteSecond(h, m, s),
+            [Num(h), Num(m), Num(s), SecondFraction(nano)] => Time::Fractional(h, m, s, nano),
+            [ClockDigits(h), ClockDigits(m)] => Time::HourMinute(h, m),
+            [Num(h), ClockDigits(m)] => Time::HourMinute(h, m),
+            [Num(h)] => Time::HourMinute(h, 0),
+        ))
+    }
+
+    fn ClockDigits(input: Node) -> ParserResult<u32> {
+        input.as_str().parse::<u32>().map_err(|e| input.error(e))
+    }
+
+    /// Returns the fraction in nanoseconds, so ".125" is 125,000,000. Digits past nanoseconds
+    /// are cut off.
+    fn SecondFraction(input: Node) -> ParserResult<u32> {
+        let digits = &input.as_str()[..input.as_str().len().min(9)];
+        let fraction = digits.parse::<u32>().map_err(|e| input.error(e))?;
+        Ok(fraction * 10u32.pow(9 - digits.len() as u32))
+    }
+
+    pub(crate) fn FourDigitHumanTime(input: Node) -> ParserResult<HumanTime> {
+        Ok(match_nodes!(input.into_children();
+            [Date(date), FourDigitTime(time)] => HumanTime::DateTime(DateTime { date, time }),
+            [FourDigitTime(time)] => HumanTime::Time(time),
+            [HumanTime(human_time)] => human_time,
+        ))
+    }
+
+    fn FourDigitTime(input: Node) -> ParserResult<Time> {
+        Ok(match_nodes!(input.into_children();
+            [ClockDigits(h), ClockDigits(m)] => Time::HourMinute(h, m),
         ))
     }
 
@@ -291,15 +446,7 @@ impl DateTimeParser {
 
     fn SingleUnit(input: Node) -> ParserResult<Quantifier> {
         Ok(match_nodes!(input.into_children();
-            [TimeUnit(u)] => match u {
-                TimeUnit::Year => Quantifier::Year(1),
-                TimeUnit::Month => Quantifier::Month(1),
-                TimeUnit::Week => Quantifier::Week(1),
-                TimeUnit::Day => Quantifier::Day(1),
-                TimeUnit::Hour => Quantifier::Hour(1),
-                TimeUnit::Minute => Quantifier::Minute(1),
-                TimeUnit::Second => Quantifier::Second(1),
-            }
+            [TimeUnit(u)] => Quantifier::new(u, 1)
         ))
     }
 
@@ -334,37 +481,34 @@ impl DateTimeParser {
 
     fn Quantifier(input: Node) -> ParserResult<Quantifier> {
         Ok(match_nodes!(input.into_children();
-            [Num(n), TimeUnit(u)] => match u {
-                TimeUnit::Year => Quantifier::Year(n),
-                TimeUnit::Month => Quantifier::Month(n),
-                TimeUnit::Week => Quantifier::Week(n),
-                TimeUnit::Day => Quantifier::Day(n),
-                TimeUnit::Hour => Quantifier::Hour(n),
-                TimeUnit::Minute => Quantifier::Minute(n),
-                TimeUnit::Second => Quantifier::Second(n),
-            },
-            [SpelledNum(n), TimeUnit(u)] => match u {
-                TimeUnit::Year => Quantifier::Year(n),
-                TimeUnit::Month => Quantifier::Month(n),
-                TimeUnit::Week => Quantifier::Week(n),
-                TimeUnit::Day => Quantifier::Day(n),
-                TimeUnit::Hour => Quantifier::Hour(n),
-                TimeUnit::Minute => Quantifier::Minute(n),
-                TimeUnit::Second => Quantifier::Second(n),
-            }
+            [Num(n), TimeUnit(u)] => Quantifier::new(u, n),
+            [SpelledNum(n), TimeUnit(u)] => Quantifier::new(u, n)
         ))
     }
 
     fn TimeUnit(input: Node) -> ParserResult<TimeUnit> {
         if let Some(rule) = input.children().next() {
             Ok(match rule.as_rule() {
+                Rule::FiscalYear => TimeUnit::FiscalYear,
+                Rule::FiscalQuarter => TimeUnit::FiscalQuarter,
+                Rule::Millennium => TimeUnit::Millennium,
+                Rule::Century => TimeUnit::Century,
+                Rule::Decade => TimeUnit::Decade,
                 Rule::Year => TimeUnit::Year,
+                Rule::Quarter => TimeUnit::Quarter,
                 Rule::Month => TimeUnit::Month,
+                Rule::SeasonUnit => TimeUnit::Season,
+                Rule::Fortnight => TimeUnit::Fortnight,
                 Rule::Week => TimeUnit::Week,
+                Rule::Sprint => TimeUnit::Sprint,
+                Rule::BusinessHour => TimeUnit::BusinessHour,
+                Rule::BusinessDay => TimeUnit::BusinessDay,
                 Rule::Day => TimeUnit::Day,
                 Rule::Hour => TimeUnit::Hour,
                 Rule::Minute => TimeUnit::Minute,
                 Rule::Second => TimeUnit::Second,
+                Rule::Millisecond => TimeUnit::Millisecond,
+                Rule::Microsecond => TimeUnit::Microsecond,
                 _ => unreachable!(),
             })
         } else {
@@ -417,13 +561,45 @@ impl DateTimeParser {
         ))
     }
 
+    fn PartOfPeriod(input: Node) -> ParserResult<(PeriodPart, DateTimeReference)> {
+        Ok(match_nodes!(input.into_children();
+            [PeriodPart(part), DateTimeReference(datetime_ref)] => (part, datetime_ref),
+        ))
+    }
+
+    fn PeriodPart(input: Node) -> ParserResult<PeriodPart> {
+        if let Some(rule) = input.children().next() {
+            Ok(match rule.as_rule() {
+                Rule::Early => PeriodPart::Early,
+                Rule::Mid => PeriodPart::Mid,
+                Rule::Late => PeriodPart::Late,
+                _ => unreachable!(),
+            })
+        } else {
+            Err(input.error("Unreachable"))
+        }
+    }
+
+    fn YearNumber(input: Node) -> ParserResult<u32> {
+        input.as_str().parse::<u32>().map_err(|e| input.error(e))
+    }
+
+    fn TwoDigitYearNumber(input: Node) -> ParserResult<u32> {
+        input.as_str().parse::<u32>().map_err(|e| input.error(e))
+    }
+
     fn DateTimeReference(input: Node) -> ParserResult<DateTimeReference> {
         Ok(match_nodes!(input.into_children();
+            [MonthSpec(month_spec), YearNumber(year)] => DateTimeReference::MonthYear(month_spec, Some(YearSpec::Absolute(year))),
+            [YearSpec(year_spec)] => DateTimeReference::Year(year_spec),
+            [YearNumber(year)] => DateTimeReference::Year(YearSpec::Absolute(year)),
             [MonthSpec(month_spec)] => DateTimeReference::MonthYear(month_spec, None),
             [MonthSpec(month_spec), YearSpec(year_spec)] => DateTimeReference::MonthYear(month_spec, Some(year_spec)),
             [Duration(duration)] => DateTimeReference::Ago(duration),
             [RelativeSpecifier(relative), TimeUnit(time_unit)] => DateTimeReference::RelativeTimeUnit(relative, time_unit),
             [TimeUnit(time_unit)] => DateTimeReference::TheTimeUnit(time_unit),
+            [QuarterSpec(spec)] => DateTimeReference::QuarterSpec(spec),
+            [SeasonSpec(spec)] => DateTimeReference::Season(spec),
             [Today(_)] => DateTimeReference::Today,
             [Tomorrow(_)] => DateTimeReference::Tomorrow,
             [Yesterday(_)] => DateTimeReference::Yesterday,
@@ -432,6 +608,60 @@ impl DateTimeParser {
         ))
     }
 
+    fn PeriodBoundary(input: Node) -> ParserResult<(Boundary, DateTimeReference)> {
+        Ok(match_nodes!(input.into_children();
+            [Boundary(boundary), DateTimeReference(datetime_ref)] => (boundary, datetime_ref),
+            [Boundary(boundary), TimeUnit(time_unit)] => (boundary, DateTimeReference::TheTimeUnit(time_unit)),
+            [EndOfPeriodAbbreviation(time_unit)] => (Boundary::End, DateTimeReference::TheTimeUnit(time_unit)),
+        ))
+    }
+
+    fn Boundary(input: Node) -> ParserResult<Boundary> {
+        if let Some(rule) = input.children().next() {
+            Ok(match rule.as_rule() {
+                Rule::Start => Boundary::Start,
+                Rule::End => Boundary::End,
+                _ => unreachable!(),
+            })
+        } else {
+            Err(input.error("Unreachable"))
+        }
+    }
+
+    fn BusinessHoursBoundary(input: Node) -> ParserResult<(Boundary, Option<Date>)> {
+        Ok(match_nodes!(input.into_children();
+            [BusinessBoundary(boundary)] => (boundary, None),
+            [BusinessBoundary(boundary), Date(date)] => (boundary, Some(date)),
+        ))
+    }
+
+    fn BusinessBoundary(input: Node) -> ParserResult<Boundary> {
+        if let Some(rule) = input.children().next() {
+            Ok(match rule.as_rule() {
+                Rule::StartOfBusiness => Boundary::Start,
+                Rule::CloseOfBusiness => Boundary::End,
+                _ => unreachable!(),
+            })
+        } else {
+            Err(input.error("Unreachable"))
+        }
+    }
+
+    fn EndOfPeriodAbbreviation(input: Node) -> ParserResult<TimeUnit> {
+        if let Some(rule) = input.children().next() {
+            Ok(match rule.as_rule() {
+                Rule::EndOfDay => TimeUnit::Day,
+                Rule::EndOfWeek => TimeUnit::Week,
+                Rule::EndOfMonth => TimeUnit::Month,
+                Rule::EndOfQuarter => TimeUnit::Quarter,
+                Rule::EndOfYear => TimeUnit::Year,
+                _ => unreachable!(),
+            })
+        } else {
+            Err(input.error("Unreachable"))
+        }
+    }
+
     fn MonthDurationFromNow(input: Node) -> ParserResult<(Month, Quantifier)> {
         Ok(match_nodes!(input.into_children();
             [Month_Name(m), Quantifier(q)] => (m, q),
@@ -469,6 +699,59 @@ impl DateTimeParser {
         }
     }
 
+    fn SeasonSpec(input: Node) -> ParserResult<SeasonSpec> {
+        Ok(match_nodes!(input.into_children();
+            [RelativeSpecifier(relative), SeasonName(season)] => SeasonSpec::Relative(relative, season),
+            [SeasonName(season), YearSpec(year)] => SeasonSpec::Year(season, Some(year)),
+            [SeasonName(season), Num(year)] => SeasonSpec::Year(season, Some(YearSpec::Absolute(year))),
+            [SeasonName(season)] => SeasonSpec::Year(season, None),
+        ))
+    }
+
+    fn SeasonName(input: Node) -> ParserResult<Season> {
+        if let Some(rule) = input.children().next() {
+            Ok(match rule.as_rule() {
+                Rule::Spring => Season::Spring,
+                Rule::Summer => Season::Summer,
+                Rule::Autumn => Season::Autumn,
+                Rule::Winter => Season::Winter,
+                _ => unreachable!(),
+            })
+        } else {
+            Err(input.error("Unreachable"))
+        }
+    }
+
+    fn MonthOfYear(input: Node) -> ParserResult<MonthOfYear> {
+        Ok(match_nodes!(input.into_children();
+            [Month_Name(month), YearNumber(year)] => MonthOfYear::Absolute(month, year),
+            [RelativeSpecifier(relative), Month_Name(month)] => MonthOfYear::Relative(relative, month),
+            [Month_Name(month)] => MonthOfYear::Relative(RelativeSpecifier::This, month),
+        ))
+    }
+
+    fn QuarterSpec(input: Node) -> ParserResult<QuarterSpec> {
+        Ok(match_nodes!(input.into_children();
+            [FiscalYearLabel(year), QuarterLabel(quarter)] => QuarterSpec::FiscalQuarter(quarter, year),
+            [QuarterLabel(quarter), FiscalYearLabel(year)] => QuarterSpec::FiscalQuarter(quarter, year),
+            [QuarterLabel(quarter), Num(year)] => QuarterSpec::Quarter(quarter, Some(year)),
+            [QuarterLabel(quarter)] => QuarterSpec::Quarter(quarter, None),
+            [FiscalYearLabel(year)] => QuarterSpec::FiscalYear(year),
+        ))
+    }
+
+    fn QuarterLabel(input: Node) -> ParserResult<u32> {
+        Ok(match_nodes!(input.into_children();
+            [Num(quarter)] => quarter,
+        ))
+    }
+
+    fn FiscalYearLabel(input: Node) -> ParserResult<u32> {
+        Ok(match_nodes!(input.into_children();
+            [Num(year)] => year,
+        ))
+    }
+
     fn YearSpec(input: Node) -> ParserResult<YearSpec> {
         Ok(match_nodes!(input.into_children();
             [RelativeSpecifier(relative)] => YearSpec::Relative(relative),
@@ -484,6 +767,8 @@ pub enum HumanTime {
     Time(Time),
     In(In),
     Ago(Ago),
+    PeriodBoundary(Boundary, DateTimeReference),
+    BusinessHoursBoundary(Boundary, Option<Date>),
     Now,
 }
 
@@ -500,6 +785,14 @@ pub struct IsoDate {
     pub day: u32,
 }
 
+/// An ISO 8601 week date like "2024-W42-3". Without a weekday it refers to the whole week.
+#[derive(Debug)]
+pub struct IsoWeekDate {
+    pub year: u32,
+    pub week: u32,
+    pub weekday: Option<u32>,
+}
+
 #[derive(Debug)]
 pub enum Date {
     Today,
@@ -507,15 +800,46 @@ pub enum Date {
     Overmorrow,
     Yesterday,
     IsoDate(IsoDate),
-    DayMonthYear(u32, Month, u32),
-    DayMonth(u32, Month),
+    IsoWeekDate(IsoWeekDate),
+    WeekNumber(u32, Option<YearSpec>),
+    DayOfYear(u32, Option<YearSpec>),
+    DayMonth(u32, Month, Option<YearSpec>),
+    DayOfMonth(u32),
+    MonthOfYear(MonthOfYear),
+    Year(u32),
     RelativeWeekWeekday(RelativeSpecifier, Weekday),
     RelativeTimeUnit(RelativeSpecifier, TimeUnit),
     RelativeWeekday(RelativeSpecifier, Weekday),
     UpcomingWeekday(Weekday),
     OrdinalTimeUnitOf(Ordinal, TimeUnit, DateTimeReference),
+    PartOfPeriod(PeriodPart, DateTimeReference),
     MonthDurationFromNow(Month, Duration),
     MonthDurationAgo(Month, Duration),
+    QuarterSpec(QuarterSpec),
+    DurationRelativeTo(Duration, Relation, Box<Date>),
+    WeekdayRelativeTo(Weekday, Relation, Box<Date>),
+    RelativeHoliday(RelativeSpecifier, String),
+    Holiday(String, Option<YearSpec>),
+    Weekend(WeekendSpec),
+    /// "Every weekday". Only its next occurrence is returned, the recurrence is dropped.
+    EveryWeekday,
+    Season(SeasonSpec),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum WeekendSpec {
+    Relative(RelativeSpecifier),
+    AfterNext,
+}
+
+#[derive(Debug)]
+struct EveryWeekday;
+
+/// Whether an expression like "2 days before Christmas" points before or after its anchor.
+#[derive(Debug, Clone, Copy)]
+pub enum Relation {
+    Before,
+    After,
 }
 
 #[derive(Debug)]
@@ -531,6 +855,8 @@ struct Overmorrow;
 pub enum Time {
     HourMinute(u32, u32),
     HourMinuteSecond(u32, u32, u32),
+    /// Hour, minute, second and the fraction of the second in nanoseconds.
+    Fractional(u32, u32, u32, u32),
 }
 
 #[derive(Debug)]
@@ -565,23 +891,97 @@ struct Last;
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub enum Quantifier {
     Year(u32),
+    Quarter(u32),
     Month(u32),
     Week(u32),
+    Sprint(u32),
+    BusinessDay(u32),
     Day(u32),
+    BusinessHour(u32),
     Hour(u32),
     Minute(u32),
     Second(u32),
+    Millisecond(u32),
+    Microsecond(u32),
+}
+
+impl Quantifier {
+    /// Fiscal units have the same length as their calendar counterparts, seasons count as three
+    /// months and fortnights as two weeks.
+    pub fn new(time_unit: TimeUnit, count: u32) -> Self {
+        match time_unit {
+            TimeUnit::Millennium => Quantifier::Year(count.saturating_mul(1000)),
+            TimeUnit::Century => Quantifier::Year(count.saturating_mul(100)),
+            TimeUnit::Decade => Quantifier::Year(count.saturating_mul(10)),
+            TimeUnit::Year | TimeUnit::FiscalYear => Quantifier::Year(count),
+            TimeUnit::Quarter | TimeUnit::FiscalQuarter => Quantifier::Quarter(count),
+            TimeUnit::Season => Quantifier::Month(count.saturating_mul(3)),
+            TimeUnit::Month => Quantifier::Month(count),
+            TimeUnit::Fortnight => Quantifier::Week(count.saturating_mul(2)),
+            TimeUnit::Week => Quantifier::Week(count),
+            TimeUnit::Sprint => Quantifier::Sprint(count),
+            TimeUnit::BusinessDay => Quantifier::BusinessDay(count),
+            TimeUnit::Day => Quantifier::Day(count),
+            TimeUnit::Hour => Quantifier::Hour(count),
+            TimeUnit::BusinessHour => Quantifier::BusinessHour(count),
+            TimeUnit::Minute => Quantifier::Minute(count),
+            TimeUnit::Second => Quantifier::Second(count),
+            TimeUnit::Millisecond => Quantifier::Millisecond(count),
+            TimeUnit::Microsecond => Quantifier::Microsecond(count),
+        }
+    }
 }
 
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
 pub enum TimeUnit {
+    FiscalYear,
+    FiscalQuarter,
+    Millennium,
+    Century,
+    Decade,
     Year,
+    Quarter,
     Month,
+    Season,
+    Fortnight,
     Week,
+    Sprint,
+    BusinessDay,
     Day,
     Hour,
+    BusinessHour,
     Minute,
     Second,
+    Millisecond,
+    Microsecond,
+}
+
+impl Display for TimeUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            TimeUnit::FiscalYear => "fiscal year",
+            TimeUnit::FiscalQuarter => "fiscal quarter",
+            TimeUnit::Millennium => "millennium",
+            TimeUnit::Century => "century",
+            TimeUnit::Decade => "decade",
+            TimeUnit::Year => "year",
+            TimeUnit::Quarter => "quarter",
+            TimeUnit::Month => "month",
+            TimeUnit::Season => "season",
+            TimeUnit::Fortnight => "fortnight",
+            TimeUnit::Week => "week",
+            TimeUnit::Sprint => "sprint",
+            TimeUnit::BusinessDay => "business day",
+            TimeUnit::Day => "day",
+            TimeUnit::Hour => "hour",
+            TimeUnit::BusinessHour => "business hour",
+            TimeUnit::Minute => "minute",
+            TimeUnit::Second => "second",
+            TimeUnit::Millisecond => "millisecond",
+            TimeUnit::Microsecond => "microsecond",
+        };
+        write!(f, "{name}")
+    }
 }
 
 #[derive(Debug)]
@@ -612,6 +1012,20 @@ impl From<Weekday> for chrono::Weekday {
 #[derive(Debug)]
 struct Week {}
 
+#[derive(Debug, Clone, Copy)]
+pub enum Boundary {
+    Start,
+    End,
+}
+
+/// The first, middle or last third of a period, as in "early March" or "mid-Q3".
+#[derive(Debug, Clone, Copy)]
+pub enum PeriodPart {
+    Early,
+    Mid,
+    Late,
+}
+
 #[derive(Debug)]
 pub enum Ordinal {
     First,
@@ -627,21 +1041,56 @@ pub enum MonthSpec {
     Current,
 }
 
+/// A month without a day. Relative months, like "in March" or "next April", refer to its next
+/// or previous occurrence.
+#[derive(Debug)]
+pub enum MonthOfYear {
+    Absolute(Month, u32),
+    Relative(RelativeSpecifier, Month),
+}
+
 #[derive(Debug)]
 pub enum YearSpec {
     Relative(RelativeSpecifier),
     Absolute(u32),
 }
 
+/// Quarters and fiscal years named by label, like "Q3 2024", "FY25 Q2" or "FY2025".
+#[derive(Debug)]
+pub enum QuarterSpec {
+    Quarter(u32, Option<u32>),
+    FiscalQuarter(u32, u32),
+    FiscalYear(u32),
+}
+
 #[derive(Debug)]
 pub enum DateTimeReference {
     MonthYear(MonthSpec, Option<YearSpec>),
+    Year(YearSpec),
     Ago(Duration),
     RelativeTimeUnit(RelativeSpecifier, TimeUnit),
     TheTimeUnit(TimeUnit),
+    QuarterSpec(QuarterSpec),
+    Season(SeasonSpec),
     Today,
     Tomorrow,
     Yesterday,
     Overmorrow,
     Now,
 }
+
+#[derive(Debug, Clone, Copy)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+/// A season like "next summer" or "winter 2024". Without a year it refers to the current or
+/// upcoming season.
+#[derive(Debug)]
+pub enum SeasonSpec {
+    Relative(RelativeSpecifier, Season),
+    Year(Season, Option<YearSpec>),
+}
@@ -0,0 +1,304 @@
+//! Holiday calendars used to resolve named holidays like "Christmas" or "next Easter".
+//!
+//! A calendar is passed to the parser through [`ParseConfig::holiday_calendar`]. The built-in
+//! calendars [`US`], [`UK`] and [`GERMANY`] are rule based. Custom calendars can either be built
+//! from [`HolidayRule`]s with [`RuleBasedCalendar::new`] or implement [`HolidayCalendar`] directly.
+//!
+//! ```
+//! use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+//! use human_date_parser::{from_human_time_with_config, holidays, ParseConfig, ParseResult};
+//!
+//! let now = NaiveDateTime::new(
+//!     NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+//!     NaiveTime::from_hms_opt(12, 0, 0).unwrap()
+//! );
+//!
+//! let config = ParseConfig {
+//!     holiday_calendar: Some(&holidays::US),
+//!     ..ParseConfig::default()
+//! };
+//!
+//! let result = from_human_time_with_config("2 days before Thanksgiving", now, config).unwrap();
+//! match result {
+//!     ParseResult::Date(date) => assert_eq!(date, NaiveDate::from_ymd_opt(2024, 11, 26).unwrap()),
+//!     _ => unreachable!(),
+//! }
+//! ```
+//!
+//! [`ParseConfig::holiday_calendar`]: crate::ParseConfig::holiday_calendar
+
+use std::{fmt::Debug, panic::RefUnwindSafe};
+
+use chrono::{Datelike, Days, Month, NaiveDate, Weekday};
+
+/// A source of named holidays.
+///
+/// Calendars are referenced from the `Copy` [`ParseConfig`](crate::ParseConfig), so they need to
+/// live for `'static`. Calendars built at runtime can get there with [`Box::leak`]. Requiring
+/// `RefUnwindSafe` keeps `ParseConfig` unwind safe.
+pub trait HolidayCalendar: Debug + Sync + RefUnwindSafe {
+    /// Returns the date of the holiday called `name` in `year`, or `None` if the calendar does not
+    /// know it. `name` is lowercase, with words separated by single spaces.
+    fn holiday(&self, name: &str, year: i32) -> Option<NaiveDate>;
+
+    /// Returns whether `date` is a public holiday, i.e. not a business day. Calendars that don't
+    /// override this have no days off.
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let _ = date;
+        false
+    }
+
+    /// Returns the public holidays from `start` to `end`, inclusive and in order. The default
+    /// checks every day with [`is_holiday`](HolidayCalendar::is_holiday).
+    fn holidays_between(&self, start: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+        start.iter_days().take_while(|date| *date <= end).filter(|date| self.is_holiday(*date)).collect()
+    }
+}
+
+/// Calendars are compared by identity, so two configs are equal if they use the same calendar.
+impl PartialEq for dyn HolidayCalendar {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::addr_eq(self, other)
+    }
+}
+
+impl Eq for dyn HolidayCalendar {}
+
+/// How the date of a holiday is determined in a given year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HolidayRule {
+    /// The same date every year, like Christmas Day on December 25th.
+    Fixed { month: Month, day: u32 },
+    /// The nth weekday of a month, like Thanksgiving on the fourth Thursday of November.
+    NthWeekday { month: Month, weekday: Weekday, n: u8 },
+    /// The last weekday of a month, like Memorial Day on the last Monday of May.
+    LastWeekday { month: Month, weekday: Weekday },
+    /// A number of days from Easter Sunday, like Good Friday two days before it.
+    Easter { offset: i64 },
+}
+
+impl HolidayRule {
+    /// Returns the date this rule falls on in `year`.
+    pub fn date_in(&self, year: i32) -> Option<NaiveDate> {
+        match *self {
+            HolidayRule::Fixed { month, day } => {
+                NaiveDate::from_ymd_opt(year, month.number_from_month(), day)
+            }
+            HolidayRule::NthWeekday { month, weekday, n } => {
+                NaiveDate::from_weekday_of_month_opt(year, month.number_from_month(), weekday, n)
+            }
+            HolidayRule::LastWeekday { month, weekday } => {
+                let last_day = month.succ().number_from_month();
+                let year_of_next_month = if month == Month::December { year + 1 } else { year };
+                let last_day = NaiveDate::from_ymd_opt(year_of_next_month, last_day, 1)?.pred_opt()?;
+                let days_since_weekday = (7 + last_day.weekday().num_days_from_monday()
+                    - weekday.num_days_from_monday())
+                    % 7;
+                last_day.checked_sub_days(Days::new(days_since_weekday as u64))
+            }
+            HolidayRule::Easter { offset } => {
+                let easter = easter_sunday(year)?;
+                if offset.is_negative() {
+                    easter.checked_sub_days(Days::new(offset.unsigned_abs()))
+                } else {
+                    easter.checked_add_days(Days::new(offset as u64))
+                }
+            }
+        }
+    }
+}
+
+/// Computes the date of Easter Sunday in the Gregorian calendar, using the anonymous Gregorian
+/// algorithm. Returns `None` for years before the Gregorian calendar was introduced.
+pub fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    if year < 1583 {
+        return None;
+    }
+
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+/// A single named holiday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Holiday {
+    /// The lowercase names the holiday can be referred to by.
+    pub names: &'static [&'static str],
+    pub rule: HolidayRule,
+    /// Whether the holiday is a day off work, as opposed to an observance like Christmas Eve.
+    pub public_holiday: bool,
+}
+
+/// A calendar made up of a fixed list of rule based holidays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleBasedCalendar {
+    holidays: &'static [Holiday],
+}
+
+impl RuleBasedCalendar {
+    pub const fn new(holidays: &'static [Holiday]) -> Self {
+        Self { holidays }
+    }
+
+    pub fn holidays(&self) -> &'static [Holiday] {
+        self.holidays
+    }
+}
+
+impl HolidayCalendar for RuleBasedCalendar {
+    fn holiday(&self, name: &str, year: i32) -> Option<NaiveDate> {
+        self.holidays
+            .iter()
+            .find(|holiday| holiday.names.contains(&name))
+            .and_then(|holiday| holiday.rule.date_in(year))
+    }
+
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays
+            .iter()
+            .filter(|holiday| holiday.public_holiday)
+            .any(|holiday| holiday.rule.date_in(date.year()) == Some(date))
+    }
+
+    fn holidays_between(&self, start: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+        let mut dates: Vec<_> = (start.year()..=end.year())
+            .flat_map(|year| {
+                self.holidays
+                    .iter()
+                    .filter(|holiday| holiday.public_holiday)
+                    .filter_map(move |holiday| holiday.rule.date_in(year))
+            })
+            .filter(|date| (start..=end).contains(date))
+            .collect();
+        dates.sort();
+        dates.dedup();
+        dates
+    }
+}
+
+const fn fixed(names: &'static [&'static str], month: Month, day: u32, public_holiday: bool) -> Holiday {
+    Holiday {
+        names,
+        rule: HolidayRule::Fixed { month, day },
+        public_holiday,
+    }
+}
+
+const fn nth_weekday(names: &'static [&'static str], n: u8, weekday: Weekday, month: Month) -> Holiday {
+    Holiday {
+        names,
+        rule: HolidayRule::NthWeekday { month, weekday, n },
+        public_holiday: true,
+    }
+}
+
+const fn last_weekday(names: &'static [&'static str], weekday: Weekday, month: Month) -> Holiday {
+    Holiday {
+        names,
+        rule: HolidayRule::LastWeekday { month, weekday },
+        public_holiday: true,
+    }
+}
+
+const fn easter(names: &'static [&'static str], offset: i64, public_holiday: bool) -> Holiday {
+    Holiday {
+        names,
+        rule: HolidayRule::Easter { offset },
+        public_holiday,
+    }
+}
+
+/// Federal holidays of the United States, along with Easter and a few common observances.
+pub static US: RuleBasedCalendar = RuleBasedCalendar::new(&[
+    fixed(&["new year's day", "new years day", "new year"], Month::January, 1, true),
+    nth_weekday(
+        &["martin luther king jr. day", "martin luther king day", "mlk day"],
+        3,
+        Weekday::Mon,
+        Month::January,
+    ),
+    nth_weekday(
+        &["presidents' day", "presidents day", "washington's birthday"],
+        3,
+        Weekday::Mon,
+        Month::February,
+    ),
+    last_weekday(&["memorial day"], Weekday::Mon, Month::May),
+    fixed(&["juneteenth"], Month::June, 19, true),
+    fixed(&["independence day"], Month::July, 4, true),
+    nth_weekday(&["labor day", "labour day"], 1, Weekday::Mon, Month::September),
+    nth_weekday(&["columbus day", "indigenous peoples' day"], 2, Weekday::Mon, Month::October),
+    fixed(&["veterans day", "veterans' day"], Month::November, 11, true),
+    nth_weekday(&["thanksgiving", "thanksgiving day"], 4, Weekday::Thu, Month::November),
+    fixed(&["christmas", "christmas day", "xmas"], Month::December, 25, true),
+    fixed(&["valentine's day", "valentines day"], Month::February, 14, false),
+    easter(&["easter", "easter sunday"], 0, false),
+    fixed(&["halloween"], Month::October, 31, false),
+    fixed(&["christmas eve"], Month::December, 24, false),
+    fixed(&["new year's eve", "new years eve"], Month::December, 31, false),
+]);
+
+/// Bank holidays of England and Wales, along with Easter and a few common observances.
+pub static UK: RuleBasedCalendar = RuleBasedCalendar::new(&[
+    fixed(&["new year's day", "new years day", "new year"], Month::January, 1, true),
+    easter(&["good friday"], -2, true),
+    easter(&["easter monday"], 1, true),
+    nth_weekday(&["early may bank holiday", "may day"], 1, Weekday::Mon, Month::May),
+    last_weekday(&["spring bank holiday"], Weekday::Mon, Month::May),
+    last_weekday(&["summer bank holiday", "august bank holiday"], Weekday::Mon, Month::August),
+    fixed(&["christmas", "christmas day", "xmas"], Month::December, 25, true),
+    fixed(&["boxing day"], Month::December, 26, true),
+    easter(&["easter", "easter sunday"], 0, false),
+    easter(&["mothering sunday", "mother's day", "mothers day"], -21, false),
+    fixed(&["valentine's day", "valentines day"], Month::February, 14, false),
+    fixed(&["halloween"], Month::October, 31, false),
+    fixed(&["bonfire night", "guy fawkes night"], Month::November, 5, false),
+    fixed(&["christmas eve"], Month::December, 24, false),
+    fixed(&["new year's eve", "new years eve"], Month::December, 31, false),
+]);
+
+/// Nationwide public holidays of Germany, by their German and English names.
+pub static GERMANY: RuleBasedCalendar = RuleBasedCalendar::new(&[
+    fixed(&["neujahr", "new year's day", "new years day", "new year"], Month::January, 1, true),
+    easter(&["karfreitag", "good friday"], -2, true),
+    easter(&["ostermontag", "easter monday"], 1, true),
+    fixed(&["tag der arbeit", "labour day", "labor day", "may day"], Month::May, 1, true),
+    easter(&["christi himmelfahrt", "ascension day"], 39, true),
+    easter(&["pfingstmontag", "whit monday"], 50, true),
+    fixed(
+        &["tag der deutschen einheit", "german unity day"],
+        Month::October,
+        3,
+        true,
+    ),
+    fixed(
+        &["erster weihnachtstag", "weihnachten", "christmas", "christmas day"],
+        Month::December,
+        25,
+        true,
+    ),
+    fixed(
+        &["zweiter weihnachtstag", "boxing day", "st. stephen's day"],
+        Month::December,
+        26,
+        true,
+    ),
+    easter(&["ostersonntag", "ostern", "easter", "easter sunday"], 0, false),
+    easter(&["pfingstsonntag", "pfingsten", "whit sunday", "pentecost"], 49, false),
+    fixed(&["heiligabend", "christmas eve"], Month::December, 24, false),
+    fixed(&["silvester", "new year's eve", "new years eve"], Month::December, 31, false),
+]);
@@ -1,24 +1,92 @@
 use std::fmt::Display;
 
 use ast::{
-    build_ast_from, Ago, Date, DateTime, Duration as AstDuration, In, IsoDate, Quantifier,
-    RelativeSpecifier, Time, TimeUnit, Ordinal, DateTimeReference, MonthSpec, YearSpec,
+    build_ast_from, build_ast_with_span, Ago, Boundary, Date, DateTime, Duration as AstDuration, In, IsoDate, IsoWeekDate, Quantifier,
+    RelativeSpecifier, Time, TimeUnit, Ordinal, DateTimeReference, MonthSpec, YearSpec, QuarterSpec,
+    Relation, WeekendSpec, PeriodPart, MonthOfYear,
 };
+use seasons::{resolve_season_spec, season_containing};
 use chrono::{
     Datelike, Days, Duration as ChronoDuration, Month, Months, NaiveDate, NaiveDateTime,
-    NaiveTime, Weekday,
+    NaiveTime, Timelike, Weekday, WeekdaySet,
 };
 use thiserror::Error;
 
+pub use holidays::HolidayCalendar;
+pub use outcome::{ParseOutcome, Precision, Tense};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ParseConfig {
     pub week_start_day: WeekStartDay,
+    pub period_end: PeriodEnd,
+    /// The month fiscal years start in. Used whenever "fiscal" or "FY" appears in the input.
+    pub fiscal_year_start: Month,
+    pub week_numbering: WeekNumbering,
+    /// Resolves holiday names like "Christmas" or "Labor Day". Without a calendar holiday names
+    /// are rejected.
+    pub holiday_calendar: Option<&'static dyn HolidayCalendar>,
+    /// The days that make up the weekend. Business days skip them.
+    pub weekend: WeekdaySet,
+    pub evaluation_mode: EvaluationMode,
+    /// Used for working hours and "close of business". Applies to every business day.
+    pub business_hours: BusinessHours,
+    /// Defines the sprints used by "next sprint" or "end of this sprint". Without it sprints are
+    /// rejected.
+    pub sprints: Option<Sprints>,
+    pub seasons: SeasonBoundaries,
+    pub hemisphere: Hemisphere,
+    pub preference: Preference,
+    pub relative_weekdays: RelativeWeekdays,
+    pub day_overflow: DayOverflow,
+    pub two_digit_years: TwoDigitYears,
+    /// The time of day given to dates by `ParseResult::to_datetime_with_config`,
+    /// `ParseOutcome::datetime` and durations subtracted from a date, like "12 hours ago at
+    /// tomorrow".
+    pub time_fill: TimeFill,
+    /// What "the 31st" resolves to in months that are shorter than that.
+    pub missing_day: MissingDay,
+    pub hour_24: Hour24,
+    pub four_digit_numbers: FourDigitNumbers,
 }
 
 impl Default for ParseConfig {
     fn default() -> Self {
         Self {
             week_start_day: WeekStartDay::Sunday,
+            period_end: PeriodEnd::LastDay,
+            fiscal_year_start: Month::January,
+            week_numbering: WeekNumbering::Iso,
+            holiday_calendar: None,
+            weekend: WeekdaySet::from_array([Weekday::Sat, Weekday::Sun]),
+            evaluation_mode: EvaluationMode::Point,
+            business_hours: BusinessHours::default(),
+            sprints: None,
+            seasons: SeasonBoundaries::Meteorological,
+            hemisphere: Hemisphere::Northern,
+            preference: Preference::Future,
+            relative_weekdays: RelativeWeekdays::Nearest,
+            day_overflow: DayOverflow::Clamp,
+            two_digit_years: TwoDigitYears::Sliding { years_ahead: 50 },
+            time_fill: TimeFill::Now,
+            missing_day: MissingDay::Skip,
+            hour_24: Hour24::NextDay,
+            four_digit_numbers: FourDigitNumbers::Years,
+        }
+    }
+}
+
+/// The daily opening hours of a business, 9:00 to 17:00 by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BusinessHours {
+    pub open: NaiveTime,
+    pub close: NaiveTime,
+}
+
+impl Default for BusinessHours {
+    fn default() -> Self {
+        Self {
+            open: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            close: NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
         }
     }
 }
@@ -29,7 +97,118 @@ pub enum WeekStartDay {
     Monday,
 }
 
+/// How "week 42" and similar week numbers are counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeekNumbering {
+    /// ISO 8601 weeks: they start on Monday and week 1 contains the first Thursday of the year.
+    Iso,
+    /// Weeks start on `ParseConfig::week_start_day` and week 1 contains January 1st.
+    Us,
+}
+
+/// Back to back sprints, or iterations, of equal length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sprints {
+    /// The first day of any sprint. All other sprints are counted from it.
+    pub anchor: NaiveDate,
+    pub length_days: u32,
+}
+
+/// Where seasons like "next summer" start and end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeasonBoundaries {
+    /// Seasons are made of whole months, e.g. spring is March to May in the northern hemisphere.
+    Meteorological,
+    /// Seasons start on the equinoxes and solstices, e.g. spring starts around March 20th.
+    Astronomical,
+}
+
+/// The hemisphere seasons are named for. Summer in one is winter in the other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hemisphere {
+    Northern,
+    Southern,
+}
+
+/// Which occurrence expressions that leave out part of a date resolve to: bare weekdays like
+/// "Friday", dates without a year like "7 February" or "Christmas", days of the month like
+/// "the 5th" and times like "15:00". Except for weekdays, the current day or time counts as both
+/// future and past. The anchor of "2 weeks ago at 10:00" is taken as today's.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preference {
+    Future,
+    Past,
+    /// Whichever occurrence is closest, preferring the future one on a tie.
+    Nearest,
+}
+
+/// What "this Friday", "next Friday" and "last Friday" refer to. "Next week Friday" always uses
+/// calendar weeks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelativeWeekdays {
+    /// "This Friday" is today or the upcoming Friday, "next Friday" the first one after today and
+    /// "last Friday" the last one before today.
+    Nearest,
+    /// The Friday of the current, following or previous week, as started by
+    /// `ParseConfig::week_start_day`. On a Wednesday, "next Friday" is 9 days away.
+    CalendarWeek,
+}
+
+/// What adding months or years does when the target month is too short for the day, like
+/// "in 1 month" on January 31st or "a year ago" on February 29th.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayOverflow {
+    /// Use the last day of the target month: January 31st plus a month is February 28th.
+    Clamp,
+    /// Carry the extra days into the following month: January 31st plus a month is March 3rd.
+    NextMonth,
+    /// Fail with `ProcessingError::InvalidDate`.
+    Error,
+}
+
+/// How years written with one or two digits, like "7 Feb 15", "Q3 24" or "FY25", are expanded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwoDigitYears {
+    /// The latest year with those last digits that is at most `years_ahead` years after now.
+    Sliding { years_ahead: u32 },
+    /// Years below `pivot` are in the 2000s, the others in the 1900s.
+    Fixed { pivot: u32 },
+    /// Years are taken as written, so "15" is the year 15.
+    Literal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingDay {
+    /// Skip to the next month in the direction of `ParseConfig::preference` that has the day.
+    Skip,
+    /// Use the last day of the month instead.
+    Clamp,
+}
+
+/// What expressions naming a span of days, like "next weekend" or "summer 2024", resolve to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvaluationMode {
+    /// The first day of the span, returned as a `ParseResult::Date`.
+    Point,
+    /// The whole span, returned as a `ParseResult::DateRange`.
+    Range,
+}
+
+/// What expressions like "end of month" or "EOW" resolve to.
+///
+/// Periods shorter than a day, like "end of the hour", always resolve to their last instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeriodEnd {
+    /// The last day of the period, returned as a `ParseResult::Date`.
+    LastDay,
+    /// The last instant of the period's last day, returned as a `ParseResult::DateTime`.
+    LastInstant,
+}
+
 mod ast;
+pub mod holidays;
+mod outcome;
+mod seasons;
 #[cfg(test)]
 mod tests;
 
@@ -69,6 +248,34 @@ pub enum ProcessingError {
     },
     #[error("{year}-{month}-{day} is not a valid date")]
     InvalidDate { year: i32, month: u32, day: u32 },
+    #[error("There is no {unit} number {ordinal} in the {period}")]
+    OrdinalOutOfRange {
+        ordinal: u32,
+        unit: String,
+        period: String,
+    },
+    #[error("Cannot select a {unit} within a {period}")]
+    UnsupportedOrdinal { unit: String, period: String },
+    #[error("Cannot split a {period} into early, mid and late parts")]
+    UnsupportedPeriodPart { period: String },
+    #[error("{year} has no day {day}")]
+    InvalidDayOfYear { year: i32, day: u32 },
+    #[error("{year} has no week {week}")]
+    InvalidWeek { year: i32, week: u32 },
+    #[error("{year}-W{week}-{weekday} is not a valid week date")]
+    InvalidWeekDate { year: i32, week: u32, weekday: u32 },
+    #[error("Business hours have to close after they open, not at {close} when opening at {open}")]
+    InvalidBusinessHours { open: NaiveTime, close: NaiveTime },
+    #[error("Sprints are not configured")]
+    SprintsNotConfigured,
+    #[error("The configured weekend is either empty or covers the whole week")]
+    InvalidWeekend,
+    /// Every day in the year after `date` is on the weekend or a holiday.
+    #[error("There is no business day within a year of {date}")]
+    NoBusinessDays { date: NaiveDate },
+    /// The calendar knows the holiday, but not in the year it was asked for.
+    #[error("Unknown holiday \"{name}\"")]
+    UnknownHoliday { name: String },
     #[error("Failed to parse inner human time: {0}")]
     InnerHumanTimeParse(Box<ParseError>),
 }
@@ -79,23 +286,114 @@ pub enum InternalError {
     FailedToBuildAst,
 }
 
-#[derive(Debug)]
+/// More kinds of results may be added, so matches on this need a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum ParseResult {
     DateTime(NaiveDateTime),
     Date(NaiveDate),
     Time(NaiveTime),
+    /// The first and last day of a span, like a weekend. Only returned with `EvaluationMode::Range`.
+    DateRange(NaiveDate, NaiveDate),
+    /// A month without a day, like "March 2024".
+    YearMonth(i32, Month),
+    /// A year without a month or day, like "2024".
+    Year(i32),
 }
 
 impl Display for ParseResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            ParseResult::DateRange(start, end) => write!(f, "{}/{}", start, end),
             ParseResult::DateTime(datetime) => write!(f, "{}", datetime),
             ParseResult::Date(date) => write!(f, "{}", date),
             ParseResult::Time(time) => write!(f, "{}", time),
+            ParseResult::YearMonth(year, month) => write!(f, "{:04}-{:02}", year, month.number_from_month()),
+            ParseResult::Year(year) => write!(f, "{:04}", year),
         }
     }
 }
 
+impl ParseResult {
+    /// Converts the result into a single point in time, filling in the parts it leaves out with
+    /// `fill`. Times are placed on `now`'s date. Spans like a month or a `DateRange` use their
+    /// first day, or their last day with `TimeFill::EndOfDay`.
+    ///
+    /// Returns `None` if the date is outside of the range chrono supports.
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+    /// use human_date_parser::{from_human_time, TimeFill};
+    ///
+    /// let now = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    /// let nine = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+    ///
+    /// let tomorrow = from_human_time("tomorrow", now).unwrap();
+    /// assert_eq!(
+    ///     tomorrow.to_datetime(now, TimeFill::Fixed(nine)),
+    ///     NaiveDate::from_ymd_opt(2024, 1, 16).unwrap().and_hms_opt(9, 0, 0),
+    /// );
+    /// ```
+    pub fn to_datetime(&self, now: NaiveDateTime, fill: TimeFill) -> Option<NaiveDateTime> {
+        let (first_day, last_day) = match *self {
+            ParseResult::DateTime(datetime) => return Some(datetime),
+            ParseResult::Time(time) => return Some(NaiveDateTime::new(now.date(), time)),
+            ParseResult::Date(date) => (date, date),
+            ParseResult::DateRange(start, end) => (start, end),
+            ParseResult::YearMonth(year, month) => {
+                let first_day = NaiveDate::from_ymd_opt(year, month.number_from_month(), 1)?;
+                (first_day, first_day.checked_add_months(Months::new(1))?.pred_opt()?)
+            }
+            ParseResult::Year(year) => (
+                NaiveDate::from_ymd_opt(year, 1, 1)?,
+                NaiveDate::from_ymd_opt(year, 12, 31)?,
+            ),
+        };
+
+        Some(match fill {
+            TimeFill::StartOfDay => NaiveDateTime::new(first_day, NaiveTime::MIN),
+            TimeFill::EndOfDay => NaiveDateTime::new(last_day, last_instant_of_day()),
+            TimeFill::Now => NaiveDateTime::new(first_day, now.time()),
+            TimeFill::Fixed(time) => NaiveDateTime::new(first_day, time),
+        })
+    }
+
+    /// Like [`to_datetime`](ParseResult::to_datetime), using `ParseConfig::time_fill`.
+    pub fn to_datetime_with_config(&self, now: NaiveDateTime, config: ParseConfig) -> Option<NaiveDateTime> {
+        self.to_datetime(now, config.time_fill)
+    }
+}
+
+/// What "24:00", as in "until 24:00 on Friday", means.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hour24 {
+    /// The start of the following day.
+    NextDay,
+    /// The last instant of the day itself.
+    EndOfDay,
+}
+
+/// What a bare four-digit number like "1530" is. Followed by "hours" or "hrs" it is always a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FourDigitNumbers {
+    Years,
+    /// Times on the 24-hour clock, when on their own or after a date: "1530", "tomorrow at 0600".
+    /// Right after a day and month, as in "March 5 1530", the number is still a year, and so are
+    /// numbers that aren't valid times, like "1999".
+    Times,
+}
+
+/// The time of day `ParseResult::to_datetime` gives results without one, like "tomorrow".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeFill {
+    StartOfDay,
+    /// The last instant of the day.
+    EndOfDay,
+    /// The time of day of `now`.
+    Now,
+    Fixed(NaiveTime),
+}
+
 /// Parses a human-readable date or time string and converts it into a structured date/time format.
 ///
 /// This function takes a string representing a human-readable date/time expression (e.g.,
@@ -113,7 +411,10 @@ impl Display for ParseResult {
 ///
 /// - `Ok(ParseResult::DateTime(dt))` if the input string represents a full date and time.
 /// - `Ok(ParseResult::Date(d))` if the input string represents only a date.
-/// - `Ok(ParseResult::Time(t))` if the input string represents only a time.
+/// - `Ok(ParseResult::Time(t))` if the input string represents only a time, and it falls on today.
+///   Times that `ParseConfig::preference` moves to another day are returned as a `DateTime`.
+/// - `Ok(ParseResult::YearMonth(y, m))` or `Ok(ParseResult::Year(y))` if the input string names a
+///   month or year without a day.
 /// - `Err(ParseError)` if parsing fails due to an unrecognized or invalid format.
 ///
 /// # Errors
@@ -181,43 +482,128 @@ pub fn from_human_time(str: &str, now: NaiveDateTime) -> Result<ParseResult, Par
 /// // Custom config (Monday as first day of week)
 /// let config = ParseConfig {
 ///     week_start_day: WeekStartDay::Monday,
+///     ..ParseConfig::default()
 /// };
 /// let result = from_human_time_with_config("1st day of last week", now, config).unwrap();
 /// ```
 pub fn from_human_time_with_config(str: &str, now: NaiveDateTime, config: ParseConfig) -> Result<ParseResult, ParseError> {
     let lowercase = str.to_lowercase();
-    let parsed = build_ast_from(&lowercase)?;
+    let parsed = build_ast_from(&lowercase, config.four_digit_numbers == FourDigitNumbers::Times)?;
 
     parse_human_time(parsed, now, config)
 }
 
+/// Like [`from_human_time_with_config`], but also describes the result: its precision, whether it
+/// depends on `now`, whether it lies in the past or future and which part of the input was parsed.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use human_date_parser::{from_human_time_detailed, ParseConfig, ParseResult, Precision, Tense};
+///
+/// let now = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap().and_hms_opt(12, 0, 0).unwrap();
+/// let outcome = from_human_time_detailed("March 2023", now, ParseConfig::default()).unwrap();
+///
+/// assert_eq!(outcome.value, ParseResult::YearMonth(2023, chrono::Month::March));
+/// assert_eq!(outcome.precision, Precision::Month);
+/// assert!(!outcome.relative);
+/// assert_eq!(outcome.tense, Tense::Past);
+/// assert_eq!(outcome.span, 0..10);
+/// ```
+pub fn from_human_time_detailed(
+    str: &str,
+    now: NaiveDateTime,
+    config: ParseConfig,
+) -> Result<ParseOutcome, ParseError> {
+    let lowercase = str.to_lowercase();
+    let (parsed, span) = build_ast_with_span(&lowercase, config.four_digit_numbers == FourDigitNumbers::Times)?;
+    let relative = outcome::is_relative(&parsed, config);
+    let time_precision = outcome::human_time_precision(&parsed);
+
+    let value = parse_human_time(parsed, now, config)?;
+    Ok(ParseOutcome {
+        precision: outcome::precision(&value, time_precision),
+        tense: outcome::tense(&value, now),
+        datetime: value.to_datetime_with_config(now, config),
+        value,
+        relative,
+        span,
+    })
+}
+
 fn parse_human_time(parsed: ast::HumanTime, now: NaiveDateTime, config: ParseConfig) -> Result<ParseResult, ParseError> {
+    // The grammar takes any run of words for a holiday name, so input is only a holiday if the
+    // calendar knows it.
+    if !holiday_names(&parsed).into_iter().all(|(name, year)| is_known_holiday(name, year, &now, config)) {
+        return Err(ParseError::InvalidFormat);
+    }
+
     match parsed {
         ast::HumanTime::DateTime(date_time) => {
-            parse_date_time(date_time, &now, config).map(|dt| ParseResult::DateTime(dt))
+            parse_date_time(date_time, &now, config).map(ParseResult::DateTime)
         }
-        ast::HumanTime::Date(date) => parse_date(date, &now, config)
-            .map(|date| ParseResult::Date(date))
+        ast::HumanTime::Date(date) => parse_date_or_range(date, &now, config)
             .map_err(|err| ParseError::ProccessingErrors(vec![err])),
-        ast::HumanTime::Time(time) => parse_time(time)
-            .map(|time| ParseResult::Time(time))
+        ast::HumanTime::Time(time) => parse_time_only(time, &now, config)
             .map_err(|err| ParseError::ProccessingErrors(vec![err])),
-        ast::HumanTime::In(in_ast) => parse_in(in_ast, &now)
-            .map(|time| ParseResult::DateTime(time))
+        ast::HumanTime::In(in_ast) => parse_in(in_ast, &now, config)
+            .map(ParseResult::DateTime)
             .map_err(|err| ParseError::ProccessingErrors(vec![err])),
         ast::HumanTime::Ago(ago) => parse_ago(ago, &now, config)
-            .map(|time| ParseResult::DateTime(time))
+            .map(ParseResult::DateTime)
             .map_err(|err| ParseError::ProccessingErrors(vec![err])),
+        ast::HumanTime::PeriodBoundary(boundary, datetime_reference) => {
+            parse_period_boundary(boundary, &datetime_reference, &now, config)
+                .map_err(|err| ParseError::ProccessingErrors(vec![err]))
+        }
+        ast::HumanTime::BusinessHoursBoundary(boundary, date) => {
+            parse_business_hours_boundary(boundary, date, &now, config)
+                .map(ParseResult::DateTime)
+                .map_err(|err| ParseError::ProccessingErrors(vec![err]))
+        }
         ast::HumanTime::Now => Ok(ParseResult::DateTime(now)),
     }
 }
 
+/// "Close of business" is the closing time of the given day, or today. Days that aren't business
+/// days roll forward to the next business day.
+fn parse_business_hours_boundary(
+    boundary: Boundary,
+    date: Option<Date>,
+    now: &NaiveDateTime,
+    config: ParseConfig,
+) -> Result<NaiveDateTime, ProcessingError> {
+    let BusinessHours { open, close } = valid_business_hours(config)?;
+    let date = match date {
+        Some(date) => parse_date(date, now, config)?,
+        None => now.date(),
+    };
+    let date = if is_business_day(date, config) {
+        date
+    } else {
+        add_business_days(date, 1, &Direction::Forwards, config)?
+    };
+
+    Ok(NaiveDateTime::new(date, match boundary {
+        Boundary::Start => open,
+        Boundary::End => close,
+    }))
+}
+
 fn parse_date_time(date_time: DateTime, now: &NaiveDateTime, config: ParseConfig) -> Result<NaiveDateTime, ParseError> {
     let date = parse_date(date_time.date, now, config);
-    let time = parse_time(date_time.time);
+    let time = parse_time(date_time.time, config);
 
     match (date, time) {
-        (Ok(date), Ok(time)) => Ok(NaiveDateTime::new(date, time)),
+        (Ok(date), Ok((time, days))) => date
+            .checked_add_days(Days::new(days))
+            .map(|date| NaiveDateTime::new(date, time))
+            .ok_or(ParseError::ProccessingErrors(vec![ProcessingError::AddToDate {
+                unit: "days".to_string(),
+                count: days as u32,
+                date: NaiveDateTime::new(date, time),
+            }])),
         (Ok(_), Err(time_error)) => Err(ParseError::ProccessingErrors(vec![time_error])),
         (Err(date_error), Ok(_)) => Err(ParseError::ProccessingErrors(vec![date_error])),
         (Err(date_error), Err(time_error)) => {
@@ -226,6 +612,37 @@ fn parse_date_time(date_time: DateTime, now: &NaiveDateTime, config: ParseConfig
     }
 }
 
+/// Like `parse_date`, but resolves dates naming a span of days to the whole span when
+/// `ParseConfig::evaluation_mode` asks for it.
+fn parse_date_or_range(date: Date, now: &NaiveDateTime, config: ParseConfig) -> Result<ParseResult, ProcessingError> {
+    match (config.evaluation_mode, date) {
+        (EvaluationMode::Range, Date::Weekend(spec)) => {
+            weekend_bounds(spec, now.date(), config).map(|(start, end)| ParseResult::DateRange(start, end))
+        }
+        (EvaluationMode::Range, Date::PartOfPeriod(part, datetime_reference)) => {
+            part_of_period_bounds(part, &datetime_reference, now, config)
+                .map(|(start, end)| ParseResult::DateRange(start, end))
+        }
+        (EvaluationMode::Range, Date::Season(spec)) => {
+            resolve_season_spec(&spec, now, config).map(|(start, end)| ParseResult::DateRange(start, end))
+        }
+        (mode, Date::MonthOfYear(spec)) => {
+            let (year, month) = resolve_month_of_year(&spec, now);
+            match mode {
+                EvaluationMode::Point => Ok(ParseResult::YearMonth(year, month)),
+                EvaluationMode::Range => period_bounds(TimeUnit::Month, parse_day_month_year(1, month, year)?, config)
+                    .map(|(start, end)| ParseResult::DateRange(start, end)),
+            }
+        }
+        (mode, Date::Year(year)) => match mode {
+            EvaluationMode::Point => Ok(ParseResult::Year(year as i32)),
+            EvaluationMode::Range => period_bounds(TimeUnit::Year, parse_day_month_year(1, Month::January, year as i32)?, config)
+                .map(|(start, end)| ParseResult::DateRange(start, end)),
+        },
+        (_, date) => parse_date(date, now, config).map(ParseResult::Date),
+    }
+}
+
 fn parse_date(date: Date, now: &NaiveDateTime, config: ParseConfig) -> Result<NaiveDate, ProcessingError> {
     match date {
         Date::Today => Ok(now.date()),
@@ -254,29 +671,230 @@ fn parse_date(date: Date, now: &NaiveDateTime, config: ParseConfig) -> Result<Na
                 })
         }
         Date::IsoDate(iso_date) => parse_iso_date(iso_date),
-        Date::DayMonthYear(day, month, year) => parse_day_month_year(day, month, year as i32),
-        Date::DayMonth(day, month) => parse_day_month_year(day, month, now.year()),
+        Date::IsoWeekDate(iso_week_date) => parse_iso_week_date(iso_week_date),
+        Date::WeekNumber(week, year_spec) => parse_week_number(week, year_spec, now, config),
+        Date::DayOfYear(day, year_spec) => parse_day_of_year(day, year_spec, now, config),
+        Date::DayMonth(day, month, Some(year_spec)) => {
+            parse_day_month_year(day, month, resolve_year_spec(&year_spec, now, config))
+        }
+        Date::DayMonth(day, month, None) => {
+            let this_year = now.year();
+            // Wide enough to reach the previous and next February 29th, up to 8 years apart
+            // around centuries.
+            let candidates = (this_year - 8..=this_year + 8)
+                .filter_map(|year| parse_day_month_year(day, month, year).ok());
+            pick_occurrence(candidates, now.date(), config.preference)
+                .ok_or(ProcessingError::InvalidDate {
+                    year: this_year,
+                    month: month.number_from_month(),
+                    day,
+                })
+        }
+        Date::DayOfMonth(day) => parse_day_of_month(day, now, config),
+        Date::MonthOfYear(spec) => {
+            let (year, month) = resolve_month_of_year(&spec, now);
+            parse_day_month_year(1, month, year)
+        }
+        Date::Year(year) => parse_day_month_year(1, Month::January, year as i32),
         Date::RelativeWeekWeekday(relative, weekday) => {
-            find_weekday_relative_week(relative, weekday.into(), now.date())
+            find_weekday_relative_week(relative, weekday.into(), now.date(), config)
         }
         Date::RelativeWeekday(relative, weekday) => {
-            find_weekday_relative(relative, weekday.into(), now.date())
+            match config.relative_weekdays {
+                RelativeWeekdays::Nearest => find_weekday_relative(relative, weekday.into(), now.date()),
+                RelativeWeekdays::CalendarWeek => {
+                    find_weekday_relative_week(relative, weekday.into(), now.date(), config)
+                }
+            }
         }
         Date::RelativeTimeUnit(relative, time_unit) => {
-            Ok(relative_date_time_unit(relative, time_unit, now.clone())?.date())
+            Ok(relative_date_time_unit(relative, time_unit, *now, config)?.date())
         }
         Date::UpcomingWeekday(weekday) => {
-            find_weekday_relative(RelativeSpecifier::Next, weekday.into(), now.date())
+            let weekday = weekday.into();
+            let candidates = [
+                find_weekday_relative(RelativeSpecifier::Next, weekday, now.date())?,
+                find_weekday_relative(RelativeSpecifier::Last, weekday, now.date())?,
+            ];
+            Ok(pick_occurrence(candidates, now.date(), config.preference)
+                .expect("Bare weekdays have a future and a past candidate"))
         }
         Date::OrdinalTimeUnitOf(ordinal, time_unit, datetime_reference) => {
             parse_ordinal_time_unit_of(&ordinal, &time_unit, &datetime_reference, now, config)
         }
+        Date::PartOfPeriod(part, datetime_reference) => {
+            let (start, end) = part_of_period_bounds(part, &datetime_reference, now, config)?;
+            Ok(start + (end - start) / 2)
+        }
         Date::MonthDurationFromNow(month, duration) => {
-            parse_month_duration_from_now(&month, &duration, now)
+            parse_month_duration_from_now(&month, &duration, now, config)
         }
         Date::MonthDurationAgo(month, duration) => {
-            parse_month_duration_ago(&month, &duration, now)
+            parse_month_duration_ago(&month, &duration, now, config)
+        }
+        Date::QuarterSpec(spec) => parse_quarter_spec(&spec, now, config),
+        Date::DurationRelativeTo(duration, relation, date) => {
+            let anchor = NaiveDateTime::new(parse_date(*date, now, config)?, NaiveTime::MIN);
+            let direction = match relation {
+                Relation::Before => Direction::Backwards,
+                Relation::After => Direction::Forwards,
+            };
+            Ok(apply_duration(duration, anchor, direction, config)?.date())
         }
+        Date::WeekdayRelativeTo(weekday, relation, date) => {
+            let anchor = parse_date(*date, now, config)?;
+            match relation {
+                Relation::Before => find_weekday_relative(RelativeSpecifier::Last, weekday.into(), anchor),
+                Relation::After => find_weekday_relative(RelativeSpecifier::Next, weekday.into(), anchor),
+            }
+        }
+        Date::RelativeHoliday(relative, name) => parse_relative_holiday(relative, &name, now, config),
+        Date::Holiday(name, Some(year_spec)) => find_holiday(&name, resolve_year_spec(&year_spec, now, config), config),
+        Date::Holiday(name, None) => {
+            let this_year = now.year();
+            let candidates = (this_year - 1..=this_year + 1)
+                .filter_map(|year| find_holiday(&name, year, config).ok());
+            pick_occurrence(candidates, now.date(), config.preference)
+                .ok_or(ProcessingError::UnknownHoliday { name })
+        }
+        Date::Weekend(spec) => Ok(weekend_bounds(spec, now.date(), config)?.0),
+        Date::EveryWeekday => next_weekday(now.date(), config),
+        Date::Season(spec) => Ok(resolve_season_spec(&spec, now, config)?.0),
+    }
+}
+
+/// Returns the first and last day of the weekend named by `spec`. "This weekend" is the current
+/// weekend if today is part of one, otherwise the upcoming one.
+fn weekend_bounds(
+    spec: WeekendSpec,
+    today: NaiveDate,
+    config: ParseConfig,
+) -> Result<(NaiveDate, NaiveDate), ProcessingError> {
+    let weekend = config.weekend;
+    if weekend.is_empty() || weekend.len() == 7 {
+        return Err(ProcessingError::InvalidWeekend);
+    }
+
+    let mut weekday = today.weekday();
+    let mut offset = 0;
+    if weekend.contains(weekday) {
+        while weekend.contains(weekday.pred()) {
+            weekday = weekday.pred();
+            offset -= 1;
+        }
+    } else {
+        while !weekend.contains(weekday) {
+            weekday = weekday.succ();
+            offset += 1;
+        }
+    }
+
+    let mut length = 0;
+    while weekend.contains(weekday.succ()) {
+        weekday = weekday.succ();
+        length += 1;
+    }
+
+    offset += match spec {
+        WeekendSpec::Relative(RelativeSpecifier::This) => 0,
+        WeekendSpec::Relative(RelativeSpecifier::Next) => 7,
+        WeekendSpec::Relative(RelativeSpecifier::Last) => -7,
+        WeekendSpec::AfterNext => 14,
+    };
+
+    let start = today.checked_add_signed(ChronoDuration::days(offset))
+        .ok_or(ProcessingError::AddToNow {
+            unit: "days".to_string(),
+            count: offset.unsigned_abs() as u32,
+        })?;
+    let end = start.checked_add_days(Days::new(length))
+        .ok_or(ProcessingError::AddToDate {
+            unit: "days".to_string(),
+            count: length as u32,
+            date: NaiveDateTime::new(start, NaiveTime::MIN),
+        })?;
+
+    Ok((start, end))
+}
+
+/// Returns today, or the next day if today is part of the weekend.
+fn next_weekday(today: NaiveDate, config: ParseConfig) -> Result<NaiveDate, ProcessingError> {
+    if config.weekend.len() == 7 {
+        return Err(ProcessingError::InvalidWeekend);
+    }
+
+    let mut date = today;
+    while config.weekend.contains(date.weekday()) {
+        date = date.succ_opt().ok_or(ProcessingError::AddToNow {
+            unit: "days".to_string(),
+            count: 1,
+        })?;
+    }
+
+    Ok(date)
+}
+
+/// The holiday names in `human_time`, with the year given for them if any.
+fn holiday_names(human_time: &ast::HumanTime) -> Vec<(&str, Option<&YearSpec>)> {
+    match human_time {
+        ast::HumanTime::DateTime(date_time) => date_holiday_names(&date_time.date),
+        ast::HumanTime::Date(date) | ast::HumanTime::BusinessHoursBoundary(_, Some(date)) => date_holiday_names(date),
+        ast::HumanTime::Ago(Ago::AgoFromTime(_, human_time)) => holiday_names(human_time),
+        ast::HumanTime::Time(_)
+        | ast::HumanTime::In(_)
+        | ast::HumanTime::Ago(Ago::AgoFromNow(_))
+        | ast::HumanTime::PeriodBoundary(_, _)
+        | ast::HumanTime::BusinessHoursBoundary(_, None)
+        | ast::HumanTime::Now => Vec::new(),
+    }
+}
+
+fn date_holiday_names(date: &Date) -> Vec<(&str, Option<&YearSpec>)> {
+    match date {
+        Date::Holiday(name, year_spec) => vec![(name.as_str(), year_spec.as_ref())],
+        Date::RelativeHoliday(_, name) => vec![(name.as_str(), None)],
+        Date::DurationRelativeTo(_, _, date) | Date::WeekdayRelativeTo(_, _, date) => date_holiday_names(date),
+        _ => Vec::new(),
+    }
+}
+
+/// Whether the holiday calendar has a holiday called `name` in the year it was given for, or around
+/// the current year.
+fn is_known_holiday(name: &str, year_spec: Option<&YearSpec>, now: &NaiveDateTime, config: ParseConfig) -> bool {
+    let given_year = year_spec.map(|year_spec| resolve_year_spec(year_spec, now, config));
+    config.holiday_calendar.is_some_and(|calendar| {
+        given_year
+            .into_iter()
+            .chain(now.year() - 1..=now.year() + 1)
+            .any(|year| calendar.holiday(name, year).is_some())
+    })
+}
+
+fn find_holiday(name: &str, year: i32, config: ParseConfig) -> Result<NaiveDate, ProcessingError> {
+    config
+        .holiday_calendar
+        .and_then(|calendar| calendar.holiday(name, year))
+        .ok_or_else(|| ProcessingError::UnknownHoliday {
+            name: name.to_string(),
+        })
+}
+
+/// "Next Easter" is the first Easter after today, "last Easter" the most recent one before today.
+fn parse_relative_holiday(
+    relative: RelativeSpecifier,
+    name: &str,
+    now: &NaiveDateTime,
+    config: ParseConfig,
+) -> Result<NaiveDate, ProcessingError> {
+    let today = now.date();
+    let this_year = find_holiday(name, today.year(), config)?;
+
+    match relative {
+        RelativeSpecifier::This => Ok(this_year),
+        RelativeSpecifier::Next if this_year > today => Ok(this_year),
+        RelativeSpecifier::Next => find_holiday(name, today.year() + 1, config),
+        RelativeSpecifier::Last if this_year < today => Ok(this_year),
+        RelativeSpecifier::Last => find_holiday(name, today.year() - 1, config),
     }
 }
 
@@ -289,6 +907,139 @@ fn parse_iso_date(iso_date: IsoDate) -> Result<NaiveDate, ProcessingError> {
     })
 }
 
+/// ISO 8601 week dates are always numbered the ISO way, regardless of `ParseConfig::week_numbering`.
+fn parse_iso_week_date(iso_week_date: IsoWeekDate) -> Result<NaiveDate, ProcessingError> {
+    let (year, week) = (iso_week_date.year as i32, iso_week_date.week);
+    let weekday = iso_week_date.weekday.unwrap_or(1);
+
+    let date = match weekday {
+        1..=7 => Weekday::try_from(weekday as u8 - 1)
+            .ok()
+            .and_then(|weekday| NaiveDate::from_isoywd_opt(year, week, weekday)),
+        _ => None,
+    };
+
+    date.ok_or(ProcessingError::InvalidWeekDate { year, week, weekday })
+}
+
+/// Returns the first day of week `week` of `year`.
+///
+/// With ISO numbering weeks start on Monday and week 1 is the week containing January 4th. With
+/// US numbering weeks start on `ParseConfig::week_start_day` and week 1 is the week containing
+/// January 1st.
+fn parse_week_number(
+    week: u32,
+    year_spec: Option<YearSpec>,
+    now: &NaiveDateTime,
+    config: ParseConfig,
+) -> Result<NaiveDate, ProcessingError> {
+    let year = year_spec.map_or(now.year(), |year_spec| resolve_year_spec(&year_spec, now, config));
+    let invalid_week = ProcessingError::InvalidWeek { year, week };
+
+    match config.week_numbering {
+        WeekNumbering::Iso => NaiveDate::from_isoywd_opt(year, week, Weekday::Mon).ok_or(invalid_week),
+        WeekNumbering::Us => {
+            let jan_1 = NaiveDate::from_ymd_opt(year, 1, 1)
+                .ok_or(ProcessingError::InvalidDate { year, month: 1, day: 1 })?;
+            let first_week = start_of_week(jan_1, config)?;
+
+            week.checked_sub(1)
+                .and_then(|offset| first_week.checked_add_days(Days::new(offset as u64 * 7)))
+                .filter(|week_start| week_start.year() <= year)
+                .ok_or(invalid_week)
+        }
+    }
+}
+
+fn parse_day_of_year(
+    day: u32,
+    year_spec: Option<YearSpec>,
+    now: &NaiveDateTime,
+    config: ParseConfig,
+) -> Result<NaiveDate, ProcessingError> {
+    let year = year_spec.map_or(now.year(), |year_spec| resolve_year_spec(&year_spec, now, config));
+    NaiveDate::from_yo_opt(year, day).ok_or(ProcessingError::InvalidDayOfYear { year, day })
+}
+
+/// "The 5th" is the closest 5th of a month in the direction of `ParseConfig::preference`.
+fn parse_day_of_month(day: u32, now: &NaiveDateTime, config: ParseConfig) -> Result<NaiveDate, ProcessingError> {
+    let today = now.date();
+    let invalid = || ProcessingError::InvalidDate {
+        year: today.year(),
+        month: today.month(),
+        day,
+    };
+    if !(1..=31).contains(&day) {
+        return Err(invalid());
+    }
+
+    let this_month = today.with_day(1).ok_or_else(invalid)?;
+    // No month is more than two months away from the next one with a given day.
+    let candidates = (0..3)
+        .filter_map(|offset| this_month.checked_sub_months(Months::new(offset)))
+        .chain((1..3).filter_map(|offset| this_month.checked_add_months(Months::new(offset))))
+        .filter_map(|month| match config.missing_day {
+            MissingDay::Skip => month.with_day(day),
+            MissingDay::Clamp => month.with_day(day).or_else(|| {
+                month.checked_add_months(Months::new(1)).and_then(|next_month| next_month.pred_opt())
+            }),
+        });
+
+    pick_occurrence(candidates, today, config.preference).ok_or_else(invalid)
+}
+
+/// Picks the candidate closest to `now` in the direction of `preference`.
+fn pick_occurrence<T>(candidates: impl IntoIterator<Item = T>, now: T, preference: Preference) -> Option<T>
+where
+    T: Copy + Ord + std::ops::Sub<Output = ChronoDuration>,
+{
+    let candidates = candidates.into_iter();
+    match preference {
+        Preference::Future => candidates.filter(|candidate| *candidate >= now).min(),
+        Preference::Past => candidates.filter(|candidate| *candidate <= now).max(),
+        Preference::Nearest => candidates.min_by_key(|candidate| ((*candidate - now).abs(), *candidate < now)),
+    }
+}
+
+/// A bare time is today's unless `ParseConfig::preference` moves it to yesterday or tomorrow, in
+/// which case the date is included in the result.
+fn parse_time_only(time: Time, now: &NaiveDateTime, config: ParseConfig) -> Result<ParseResult, ProcessingError> {
+    let (time, days) = parse_time(time, config)?;
+    let today = NaiveDateTime::new(now.date(), time).checked_add_days(Days::new(days));
+    let Some(today) = today else {
+        return Err(ProcessingError::AddToNow { unit: "days".to_string(), count: days as u32 });
+    };
+    let candidates = [
+        today.checked_sub_days(Days::new(1)),
+        Some(today),
+        today.checked_add_days(Days::new(1)),
+    ];
+
+    match pick_occurrence(candidates.into_iter().flatten(), *now, config.preference) {
+        Some(datetime) if datetime.date() != now.date() => Ok(ParseResult::DateTime(datetime)),
+        Some(datetime) => Ok(ParseResult::Time(datetime.time())),
+        None => Ok(ParseResult::Time(time)),
+    }
+}
+
+/// "In March" and "this March" include the current month, "next April" and "last April" don't.
+fn resolve_month_of_year(spec: &MonthOfYear, now: &NaiveDateTime) -> (i32, Month) {
+    match *spec {
+        MonthOfYear::Absolute(month, year) => (year as i32, month),
+        MonthOfYear::Relative(relative, month) => {
+            let target = month.number_from_month();
+            let year = match relative {
+                RelativeSpecifier::This if target >= now.month() => now.year(),
+                RelativeSpecifier::Next if target > now.month() => now.year(),
+                RelativeSpecifier::This | RelativeSpecifier::Next => now.year() + 1,
+                RelativeSpecifier::Last if target < now.month() => now.year(),
+                RelativeSpecifier::Last => now.year() - 1,
+            };
+            (year, month)
+        }
+    }
+}
+
 fn parse_day_month_year(day: u32, month: Month, year: i32) -> Result<NaiveDate, ProcessingError> {
     let month = month.number_from_month();
     NaiveDate::from_ymd_opt(year, month, day).ok_or(ProcessingError::InvalidDate {
@@ -298,13 +1049,36 @@ fn parse_day_month_year(day: u32, month: Month, year: i32) -> Result<NaiveDate,
     })
 }
 
-fn parse_time(time: Time) -> Result<NaiveTime, ProcessingError> {
+/// Returns the time of day and the number of days it lies after the day it's on. That is one for
+/// "24:00", unless `ParseConfig::hour_24` clamps it to the end of the day.
+fn parse_time(time: Time, config: ParseConfig) -> Result<(NaiveTime, u64), ProcessingError> {
+    let (hour, minute, second, nano) = match time {
+        Time::HourMinute(hour, minute) => (hour, minute, 0, 0),
+        Time::HourMinuteSecond(hour, minute, second) => (hour, minute, second, 0),
+        Time::Fractional(hour, minute, second, nano) => (hour, minute, second, nano),
+    };
+
+    match (hour, minute, second, nano, config.hour_24) {
+        (24, 0, 0, 0, Hour24::NextDay) => return Ok((NaiveTime::MIN, 1)),
+        (24, 0, 0, 0, Hour24::EndOfDay) => return Ok((last_instant_of_day(), 0)),
+        // chrono represents leap seconds as the 59th second with an extra second of nanoseconds.
+        // They only follow the last minute of an hour, which covers UTC and whole-hour offsets.
+        (_, 59, 60, _, _) => {
+            return NaiveTime::from_hms_nano_opt(hour, minute, 59, 1_000_000_000 + nano)
+                .map(|time| (time, 0))
+                .ok_or(ProcessingError::TimeHourMinuteSecond { hour, minute, second })
+        }
+        _ => {}
+    }
+
     match time {
         Time::HourMinute(hour, minute) => NaiveTime::from_hms_opt(hour, minute, 0)
+            .map(|time| (time, 0))
             .ok_or(ProcessingError::TimeHourMinute { hour, minute }),
-        Time::HourMinuteSecond(hour, minute, second) => NaiveTime::from_hms_opt(
-            hour, minute, second,
+        Time::HourMinuteSecond(..) | Time::Fractional(..) => NaiveTime::from_hms_nano_opt(
+            hour, minute, second, nano,
         )
+        .map(|time| (time, 0))
         .ok_or(ProcessingError::TimeHourMinuteSecond {
             hour,
             minute,
@@ -313,30 +1087,91 @@ fn parse_time(time: Time) -> Result<NaiveTime, ProcessingError> {
     }
 }
 
-fn parse_in(in_ast: In, now: &NaiveDateTime) -> Result<NaiveDateTime, ProcessingError> {
-    let dt = now.clone();
-    apply_duration(in_ast.0, dt, Direction::Forwards)
+fn parse_in(in_ast: In, now: &NaiveDateTime, config: ParseConfig) -> Result<NaiveDateTime, ProcessingError> {
+    let dt = *now;
+    apply_duration(in_ast.0, dt, Direction::Forwards, config)
 }
 
 fn parse_ago(ago: Ago, now: &NaiveDateTime, config: ParseConfig) -> Result<NaiveDateTime, ProcessingError> {
     match ago {
         Ago::AgoFromNow(ago) => {
-            let dt = now.clone();
-            apply_duration(ago, dt, Direction::Backwards)
+            let dt = *now;
+            apply_duration(ago, dt, Direction::Backwards, config)
         }
         Ago::AgoFromTime(ago, time) => {
-            let human_time = parse_human_time(*time, now.clone(), config)
-                .map_err(|e| ProcessingError::InnerHumanTimeParse(Box::new(e)))?;
-            let dt = match human_time {
-                ParseResult::DateTime(dt) => dt,
-                ParseResult::Date(date) => NaiveDateTime::new(date, now.time()),
-                ParseResult::Time(time) => NaiveDateTime::new(now.date(), time),
+            // Bare times and dates without a year stay on today and this year, so the preference
+            // doesn't move the anchor before the duration is subtracted.
+            let human_time = match *time {
+                ast::HumanTime::Time(time) => {
+                    let (time, days) = parse_time(time, config)?;
+                    let datetime = NaiveDateTime::new(now.date(), time)
+                        .checked_add_days(Days::new(days))
+                        .ok_or(ProcessingError::AddToNow { unit: "days".to_string(), count: days as u32 })?;
+                    ParseResult::DateTime(datetime)
+                }
+                ast::HumanTime::Date(Date::DayMonth(day, month, None)) => {
+                    ParseResult::Date(parse_day_month_year(day, month, now.year())?)
+                }
+                time => parse_human_time(time, *now, config)
+                    .map_err(|e| ProcessingError::InnerHumanTimeParse(Box::new(e)))?,
             };
-            apply_duration(ago, dt, Direction::Backwards)
+            let dt = human_time.to_datetime_with_config(*now, config).ok_or(ProcessingError::InvalidDate {
+                year: now.year(),
+                month: now.month(),
+                day: now.day(),
+            })?;
+            apply_duration(ago, dt, Direction::Backwards, config)
         }
     }
 }
 
+/// Moves `dt` by whole months, keeping its day of the month. Days the target month doesn't have,
+/// like the 31st or February 29th, are handled as set by `ParseConfig::day_overflow`.
+fn add_months(
+    dt: NaiveDateTime,
+    months: u32,
+    unit: &str,
+    count: u32,
+    direction: &Direction,
+    config: ParseConfig,
+) -> Result<NaiveDateTime, ProcessingError> {
+    let first_of_month = dt.with_day(1).expect("Every month has a first day");
+    let target_month = match direction {
+        Direction::Forwards => first_of_month.checked_add_months(Months::new(months)).ok_or(
+            ProcessingError::AddToDate {
+                unit: unit.to_string(),
+                count,
+                date: dt,
+            },
+        )?,
+        Direction::Backwards => first_of_month.checked_sub_months(Months::new(months)).ok_or(
+            ProcessingError::SubtractFromDate {
+                unit: unit.to_string(),
+                count,
+                date: dt,
+            },
+        )?,
+    };
+
+    if let Some(target) = target_month.with_day(dt.day()) {
+        return Ok(target);
+    }
+
+    let overflow = match config.day_overflow {
+        DayOverflow::Clamp => target_month
+            .checked_add_months(Months::new(1))
+            .and_then(|next_month| next_month.checked_sub_days(Days::new(1))),
+        DayOverflow::NextMonth => target_month.checked_add_days(Days::new(dt.day() as u64 - 1)),
+        DayOverflow::Error => None,
+    };
+
+    overflow.ok_or(ProcessingError::InvalidDate {
+        year: target_month.year(),
+        month: target_month.month(),
+        day: dt.day(),
+    })
+}
+
 #[derive(PartialEq, Eq)]
 enum Direction {
     Forwards,
@@ -347,67 +1182,59 @@ fn apply_duration(
     duration: AstDuration,
     mut dt: NaiveDateTime,
     direction: Direction,
+    config: ParseConfig,
 ) -> Result<NaiveDateTime, ProcessingError> {
     for quant in duration.0 {
         match quant {
             Quantifier::Year(years) => {
-                let years = years as i32;
-                if direction == Direction::Forwards {
-                    dt = dt
-                        .with_year(dt.year() + years)
-                        .ok_or(ProcessingError::InvalidDate {
-                            year: dt.year() + years,
-                            month: dt.month(),
-                            day: dt.day(),
-                        })?;
-                } else {
-                    dt = dt
-                        .with_year(dt.year() - years)
-                        .ok_or(ProcessingError::InvalidDate {
-                            year: dt.year() - years,
-                            month: dt.month(),
-                            day: dt.day(),
-                        })?;
-                }
+                dt = add_months(dt, years.saturating_mul(12), "years", years, &direction, config)?
             }
-            Quantifier::Month(months) => {
+            Quantifier::Month(months) => dt = add_months(dt, months, "months", months, &direction, config)?,
+            Quantifier::Quarter(quarters) => {
+                dt = add_months(dt, quarters.saturating_mul(3), "quarters", quarters, &direction, config)?
+            }
+            Quantifier::Week(weeks) => {
                 if direction == Direction::Forwards {
-                    dt = dt.checked_add_months(Months::new(months)).ok_or(
+                    dt = dt.checked_add_days(Days::new(weeks as u64 * 7)).ok_or(
                         ProcessingError::AddToDate {
-                            unit: "months".to_string(),
-                            count: months,
+                            unit: "weeks".to_string(),
+                            count: weeks,
                             date: dt,
                         },
                     )?
                 } else {
-                    dt = dt.checked_sub_months(Months::new(months)).ok_or(
-                        ProcessingError::SubtractFromDate {
-                            unit: "months".to_string(),
-                            count: months,
+                    dt = dt.checked_sub_days(Days::new(weeks as u64 * 7)).ok_or(
+                        ProcessingError::AddToDate {
+                            unit: "weeks".to_string(),
+                            count: weeks,
                             date: dt,
                         },
                     )?
                 }
             }
-            Quantifier::Week(weeks) => {
+            Quantifier::Sprint(sprints) => {
+                let days = sprints as u64 * sprints_config(config)?.length_days as u64;
                 if direction == Direction::Forwards {
-                    dt = dt.checked_add_days(Days::new(weeks as u64 * 7)).ok_or(
+                    dt = dt.checked_add_days(Days::new(days)).ok_or(
                         ProcessingError::AddToDate {
-                            unit: "weeks".to_string(),
-                            count: weeks,
+                            unit: "sprints".to_string(),
+                            count: sprints,
                             date: dt,
                         },
                     )?
                 } else {
-                    dt = dt.checked_sub_days(Days::new(weeks as u64 * 7)).ok_or(
-                        ProcessingError::AddToDate {
-                            unit: "weeks".to_string(),
-                            count: weeks,
+                    dt = dt.checked_sub_days(Days::new(days)).ok_or(
+                        ProcessingError::SubtractFromDate {
+                            unit: "sprints".to_string(),
+                            count: sprints,
                             date: dt,
                         },
                     )?
                 }
             }
+            Quantifier::BusinessDay(days) => {
+                dt = NaiveDateTime::new(add_business_days(dt.date(), days, &direction, config)?, dt.time())
+            }
             Quantifier::Day(days) => {
                 if direction == Direction::Forwards {
                     dt = dt.checked_add_days(Days::new(days as u64)).ok_or(
@@ -427,25 +1254,42 @@ fn apply_duration(
                     )?
                 }
             }
+            Quantifier::BusinessHour(hours) => {
+                dt = add_business_hours(dt, hours, &direction, config)?
+            }
             Quantifier::Hour(hours) => {
                 if direction == Direction::Forwards {
-                    dt = dt + ChronoDuration::hours(hours as i64)
+                    dt += ChronoDuration::hours(hours as i64)
                 } else {
-                    dt = dt - ChronoDuration::hours(hours as i64)
+                    dt -= ChronoDuration::hours(hours as i64)
                 }
             }
             Quantifier::Minute(minutes) => {
                 if direction == Direction::Forwards {
-                    dt = dt + ChronoDuration::minutes(minutes as i64)
+                    dt += ChronoDuration::minutes(minutes as i64)
                 } else {
-                    dt = dt - ChronoDuration::minutes(minutes as i64)
+                    dt -= ChronoDuration::minutes(minutes as i64)
                 }
             }
             Quantifier::Second(seconds) => {
                 if direction == Direction::Forwards {
-                    dt = dt + ChronoDuration::seconds(seconds as i64)
+                    dt += ChronoDuration::seconds(seconds as i64)
                 } else {
-                    dt = dt - ChronoDuration::seconds(seconds as i64)
+                    dt -= ChronoDuration::seconds(seconds as i64)
+                }
+            }
+            Quantifier::Millisecond(milliseconds) => {
+                if direction == Direction::Forwards {
+                    dt += ChronoDuration::milliseconds(milliseconds as i64)
+                } else {
+                    dt -= ChronoDuration::milliseconds(milliseconds as i64)
+                }
+            }
+            Quantifier::Microsecond(microseconds) => {
+                if direction == Direction::Forwards {
+                    dt += ChronoDuration::microseconds(microseconds as i64)
+                } else {
+                    dt -= ChronoDuration::microseconds(microseconds as i64)
                 }
             }
         };
@@ -454,57 +1298,222 @@ fn apply_duration(
     Ok(dt)
 }
 
+/// Moves `count` business days from `date`, skipping weekends and the holidays of
+/// `ParseConfig::holiday_calendar`.
+fn add_business_days(
+    date: NaiveDate,
+    count: u32,
+    direction: &Direction,
+    config: ParseConfig,
+) -> Result<NaiveDate, ProcessingError> {
+    let days_per_week = 7 - config.weekend.len() as u32;
+    if days_per_week == 0 {
+        return Err(ProcessingError::InvalidWeekend);
+    }
+
+    let mut current = date;
+    let mut remaining = count;
+    let mut days_without_business_day = 0;
+
+    while remaining > 0 {
+        // Whole weeks always have the same number of weekdays, so only their holidays need to be
+        // looked at. The last few days are walked one at a time.
+        let weeks = (remaining - 1) / days_per_week;
+        let days = if weeks > 0 { weeks as i64 * 7 } else { 1 };
+        let next = match direction {
+            Direction::Forwards => current.checked_add_signed(ChronoDuration::days(days)),
+            Direction::Backwards => current.checked_sub_signed(ChronoDuration::days(days)),
+        };
+        let next = next.ok_or_else(|| match direction {
+            Direction::Forwards => ProcessingError::AddToDate {
+                unit: "business days".to_string(),
+                count,
+                date: NaiveDateTime::new(date, NaiveTime::MIN),
+            },
+            Direction::Backwards => ProcessingError::SubtractFromDate {
+                unit: "business days".to_string(),
+                count,
+                date: NaiveDateTime::new(date, NaiveTime::MIN),
+            },
+        })?;
+
+        let business_days = if weeks > 0 {
+            let (first, last) = match direction {
+                Direction::Forwards => (current.succ_opt().unwrap_or(next), next),
+                Direction::Backwards => (next, current.pred_opt().unwrap_or(next)),
+            };
+            (weeks * days_per_week).saturating_sub(weekday_holidays_between(first, last, config))
+        } else {
+            is_business_day(next, config) as u32
+        };
+
+        if business_days == 0 {
+            days_without_business_day += days;
+            if days_without_business_day > 366 {
+                return Err(ProcessingError::NoBusinessDays { date });
+            }
+        } else {
+            days_without_business_day = 0;
+        }
+
+        remaining -= business_days;
+        current = next;
+    }
+
+    Ok(current)
+}
+
+/// Counts the holidays from `start` to `end` that aren't on the weekend anyway.
+fn weekday_holidays_between(start: NaiveDate, end: NaiveDate, config: ParseConfig) -> u32 {
+    config.holiday_calendar.map_or(0, |calendar| {
+        calendar
+            .holidays_between(start, end)
+            .into_iter()
+            .filter(|date| !config.weekend.contains(date.weekday()))
+            .count() as u32
+    })
+}
+
+/// Moves `count` hours within business hours from `dt`. Time outside of business hours, on
+/// weekends and on holidays is skipped, so the result rolls over to the next business day.
+fn add_business_hours(
+    dt: NaiveDateTime,
+    count: u32,
+    direction: &Direction,
+    config: ParseConfig,
+) -> Result<NaiveDateTime, ProcessingError> {
+    let BusinessHours { open, close } = valid_business_hours(config)?;
+    let hours = ChronoDuration::hours(count as i64);
+    let out_of_range = || match direction {
+        Direction::Forwards => ProcessingError::AddToDate {
+            unit: "business hours".to_string(),
+            count,
+            date: dt,
+        },
+        Direction::Backwards => ProcessingError::SubtractFromDate {
+            unit: "business hours".to_string(),
+            count,
+            date: dt,
+        },
+    };
+    let add_days = |date, days| {
+        add_business_days(date, days, direction, config).map_err(|error| match error {
+            ProcessingError::AddToDate { .. } | ProcessingError::SubtractFromDate { .. } => out_of_range(),
+            error => error,
+        })
+    };
+
+    // Where the hours start counting from, and how much of that business day is left.
+    let (start, available) = match direction {
+        Direction::Forwards => {
+            let start = if !is_business_day(dt.date(), config) || dt.time() >= close {
+                NaiveDateTime::new(add_days(dt.date(), 1)?, open)
+            } else {
+                dt.max(NaiveDateTime::new(dt.date(), open))
+            };
+            (start, NaiveDateTime::new(start.date(), close) - start)
+        }
+        Direction::Backwards => {
+            let start = if !is_business_day(dt.date(), config) || dt.time() <= open {
+                NaiveDateTime::new(add_days(dt.date(), 1)?, close)
+            } else {
+                dt.min(NaiveDateTime::new(dt.date(), close))
+            };
+            (start, start - NaiveDateTime::new(start.date(), open))
+        }
+    };
+    if hours <= available {
+        return Ok(match direction {
+            Direction::Forwards => start + hours,
+            Direction::Backwards => start - hours,
+        });
+    }
+
+    // Every further business day has the same hours, so whole days are skipped at once and only
+    // the hours of the last one are left over.
+    let nanoseconds = |duration: ChronoDuration| {
+        duration.num_seconds() as i128 * 1_000_000_000 + duration.subsec_nanos() as i128
+    };
+    let remaining = nanoseconds(hours - available);
+    let day_length = nanoseconds(close - open);
+    let days = (remaining - 1) / day_length + 1;
+    let last_day = ChronoDuration::nanoseconds((remaining - (days - 1) * day_length) as i64);
+    let date = add_days(start.date(), u32::try_from(days).map_err(|_| out_of_range())?)?;
+
+    Ok(match direction {
+        Direction::Forwards => NaiveDateTime::new(date, open) + last_day,
+        Direction::Backwards => NaiveDateTime::new(date, close) - last_day,
+    })
+}
+
+fn valid_business_hours(config: ParseConfig) -> Result<BusinessHours, ProcessingError> {
+    let BusinessHours { open, close } = config.business_hours;
+    if open < close {
+        Ok(config.business_hours)
+    } else {
+        Err(ProcessingError::InvalidBusinessHours { open, close })
+    }
+}
+
+fn is_business_day(date: NaiveDate, config: ParseConfig) -> bool {
+    let weekend = config.weekend.contains(date.weekday());
+    let holiday = config
+        .holiday_calendar
+        .is_some_and(|calendar| calendar.is_holiday(date));
+
+    !weekend && !holiday
+}
+
 fn relative_date_time_unit(
     relative: RelativeSpecifier,
     time_unit: TimeUnit,
     now: NaiveDateTime,
+    config: ParseConfig,
 ) -> Result<NaiveDateTime, ProcessingError> {
-    let quantifier = match time_unit {
-        TimeUnit::Year => Quantifier::Year(1),
-        TimeUnit::Month => Quantifier::Month(1),
-        TimeUnit::Week => Quantifier::Week(1),
-        TimeUnit::Day => Quantifier::Day(1),
-        TimeUnit::Hour | TimeUnit::Minute | TimeUnit::Second => {
-            unreachable!("Non-date time units should never be used in this function.")
-        }
-    };
+    let quantifier = Quantifier::new(time_unit, 1);
 
+    let datetime = match relative {
+        RelativeSpecifier::This => now,
+        RelativeSpecifier::Next => apply_duration(AstDuration(vec![quantifier]), now, Direction::Forwards, config)?,
+        RelativeSpecifier::Last => apply_duration(AstDuration(vec![quantifier]), now, Direction::Backwards, config)?,
+    };
 
-    match relative {
-        RelativeSpecifier::This => Ok(now),
-        RelativeSpecifier::Next => apply_duration(AstDuration(vec![quantifier]), now, Direction::Forwards),
-        RelativeSpecifier::Last => apply_duration(AstDuration(vec![quantifier]), now, Direction::Backwards),
+    match time_unit {
+        // Decades and longer are named like calendar periods, so "next decade" is the 2030s in
+        // 2024, not ten years from now.
+        TimeUnit::Decade | TimeUnit::Century | TimeUnit::Millennium => {
+            let (start, _) = period_bounds(time_unit, datetime.date(), config)?;
+            Ok(NaiveDateTime::new(start, NaiveTime::MIN))
+        }
+        _ => Ok(datetime),
     }
 }
 
+/// Finds `weekday` in the current, next or previous week, as started by `ParseConfig::week_start_day`.
 fn find_weekday_relative_week(
     relative: RelativeSpecifier,
     weekday: Weekday,
     now: NaiveDate,
+    config: ParseConfig,
 ) -> Result<NaiveDate, ProcessingError> {
-    let day_offset = -(now.weekday().num_days_from_monday() as i64);
+    let week_start = start_of_week(now, config)?;
     let week_offset = match relative {
         RelativeSpecifier::This => 0,
         RelativeSpecifier::Next => 1,
         RelativeSpecifier::Last => -1,
     } * 7;
-    let offset = day_offset + week_offset;
-
-    let now = if offset.is_positive() {
-        now.checked_add_days(Days::new(offset.unsigned_abs()))
-            .ok_or(ProcessingError::AddToNow {
-                unit: "days".to_string(),
-                count: offset.unsigned_abs() as u32,
-            })?
-    } else {
-        now.checked_sub_days(Days::new(offset.unsigned_abs()))
-            .ok_or(ProcessingError::SubtractFromNow {
-                unit: "days".to_string(),
-                count: offset.unsigned_abs() as u32,
-            })?
-    };
-
-    find_weekday_relative(RelativeSpecifier::This, weekday, now)
+    let day_offset = match config.week_start_day {
+        WeekStartDay::Sunday => weekday.num_days_from_sunday(),
+        WeekStartDay::Monday => weekday.num_days_from_monday(),
+    } as i64;
+    let offset = week_offset + day_offset;
+
+    week_start.checked_add_signed(ChronoDuration::days(offset))
+        .ok_or(ProcessingError::AddToDate {
+            unit: "days".to_string(),
+            count: offset.unsigned_abs() as u32,
+            date: NaiveDateTime::new(week_start, NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
+        })
 }
 
 fn find_weekday_relative(
@@ -515,7 +1524,7 @@ fn find_weekday_relative(
     match relative {
         RelativeSpecifier::This | RelativeSpecifier::Next => {
             if matches!(relative, RelativeSpecifier::This) && now.weekday() == weekday {
-                return Ok(now.clone());
+                return Ok(now);
             }
 
             let current_weekday = now.weekday().num_days_from_monday();
@@ -560,36 +1569,424 @@ fn parse_ordinal_time_unit_of(
     config: ParseConfig,
 ) -> Result<NaiveDate, ProcessingError> {
     let base_datetime = resolve_datetime_reference(datetime_reference, now, config)?;
-
-    if let (TimeUnit::Day, DateTimeReference::RelativeTimeUnit(_, TimeUnit::Year)) = (time_unit, datetime_reference) {
-        return apply_ordinal_to_years(ordinal, base_datetime);
+    let period = reference_period(datetime_reference);
+    let (start, end) = period_bounds(period, base_datetime.date(), config)?;
+
+    match (time_unit, period) {
+        (
+            TimeUnit::Hour | TimeUnit::Minute | TimeUnit::Second | TimeUnit::Millisecond | TimeUnit::Microsecond,
+            _,
+        ) => {
+            apply_ordinal_to_subday_units(ordinal, time_unit, start, end, period)
+        }
+        (
+            TimeUnit::Day,
+            TimeUnit::Week
+            | TimeUnit::Fortnight
+            | TimeUnit::Sprint
+            | TimeUnit::Month
+            | TimeUnit::Season
+            | TimeUnit::Quarter
+            | TimeUnit::FiscalQuarter
+            | TimeUnit::Year
+            | TimeUnit::FiscalYear
+            | TimeUnit::Decade
+            | TimeUnit::Century
+            | TimeUnit::Millennium,
+        ) => apply_ordinal_to_days(ordinal, start, end, period),
+        (
+            TimeUnit::BusinessDay,
+            TimeUnit::Week
+            | TimeUnit::Fortnight
+            | TimeUnit::Sprint
+            | TimeUnit::Month
+            | TimeUnit::Season
+            | TimeUnit::Quarter
+            | TimeUnit::FiscalQuarter
+            | TimeUnit::Year
+            | TimeUnit::FiscalYear
+            | TimeUnit::Decade
+            | TimeUnit::Century
+            | TimeUnit::Millennium,
+        ) => apply_ordinal_to_business_days(ordinal, start, end, period, config),
+        (
+            TimeUnit::Week,
+            TimeUnit::Fortnight
+            | TimeUnit::Sprint
+            | TimeUnit::Month
+            | TimeUnit::Season
+            | TimeUnit::Quarter
+            | TimeUnit::FiscalQuarter
+            | TimeUnit::Year
+            | TimeUnit::FiscalYear
+            | TimeUnit::Decade
+            | TimeUnit::Century
+            | TimeUnit::Millennium,
+        ) => apply_ordinal_to_weeks(ordinal, start, end, period),
+        (
+            TimeUnit::Month,
+            TimeUnit::Quarter
+            | TimeUnit::FiscalQuarter
+            | TimeUnit::Year
+            | TimeUnit::FiscalYear
+            | TimeUnit::Decade
+            | TimeUnit::Century
+            | TimeUnit::Millennium,
+        ) => apply_ordinal_to_months(ordinal, start, end, period),
+        (
+            TimeUnit::Quarter,
+            TimeUnit::Year | TimeUnit::FiscalYear | TimeUnit::Decade | TimeUnit::Century | TimeUnit::Millennium,
+        )
+        | (TimeUnit::FiscalQuarter, TimeUnit::FiscalYear) => {
+            apply_ordinal_to_quarters(ordinal, start, end, *time_unit, period)
+        }
+        (TimeUnit::Year, TimeUnit::Decade | TimeUnit::Century | TimeUnit::Millennium) => {
+            apply_ordinal_to_month_blocks(ordinal, start, end, 12, *time_unit, period)
+        }
+        (TimeUnit::Decade, TimeUnit::Century | TimeUnit::Millennium) => {
+            apply_ordinal_to_month_blocks(ordinal, start, end, 120, *time_unit, period)
+        }
+        (TimeUnit::Century, TimeUnit::Millennium) => {
+            apply_ordinal_to_month_blocks(ordinal, start, end, 1200, *time_unit, period)
+        }
+        _ => Err(ProcessingError::UnsupportedOrdinal {
+            unit: time_unit.to_string(),
+            period: period.to_string(),
+        }),
     }
+}
 
-    if let (TimeUnit::Day, DateTimeReference::TheTimeUnit(TimeUnit::Year)) = (time_unit, datetime_reference) {
-        return apply_ordinal_to_years(ordinal, base_datetime);
+/// Returns the kind of period a reference names, e.g. a month for "next april" or a year for
+/// "the year". Ordinals are resolved within that period.
+fn reference_period(datetime_reference: &DateTimeReference) -> TimeUnit {
+    match datetime_reference {
+        DateTimeReference::MonthYear(_, _) => TimeUnit::Month,
+        DateTimeReference::Year(_) => TimeUnit::Year,
+        DateTimeReference::QuarterSpec(spec) => quarter_spec_period(spec),
+        DateTimeReference::Season(_) => TimeUnit::Season,
+        DateTimeReference::RelativeTimeUnit(_, time_unit)
+        | DateTimeReference::TheTimeUnit(time_unit) => *time_unit,
+        // "2 months ago" names a month, "3 weeks ago" a week.
+        DateTimeReference::Ago(duration) => match duration.0.last() {
+            Some(Quantifier::Year(_)) => TimeUnit::Year,
+            Some(Quantifier::Quarter(_)) => TimeUnit::Quarter,
+            Some(Quantifier::Month(_)) => TimeUnit::Month,
+            Some(Quantifier::Week(_)) => TimeUnit::Week,
+            Some(Quantifier::Sprint(_)) => TimeUnit::Sprint,
+            _ => TimeUnit::Day,
+        },
+        DateTimeReference::Today
+        | DateTimeReference::Tomorrow
+        | DateTimeReference::Yesterday
+        | DateTimeReference::Overmorrow
+        | DateTimeReference::Now => TimeUnit::Day,
     }
+}
 
-    if let (TimeUnit::Day, DateTimeReference::RelativeTimeUnit(RelativeSpecifier::Last, TimeUnit::Week)) = (time_unit, datetime_reference) {
-        return apply_ordinal_to_weeks(ordinal, base_datetime, config);
+/// Returns the first and last day of the `period` containing `date`.
+fn period_bounds(
+    period: TimeUnit,
+    date: NaiveDate,
+    config: ParseConfig,
+) -> Result<(NaiveDate, NaiveDate), ProcessingError> {
+    match period {
+        TimeUnit::FiscalYear => month_block_bounds(date, config.fiscal_year_start, 12),
+        TimeUnit::FiscalQuarter => month_block_bounds(date, config.fiscal_year_start, 3),
+        TimeUnit::Millennium => year_block_bounds(date, 1000),
+        TimeUnit::Century => year_block_bounds(date, 100),
+        TimeUnit::Decade => year_block_bounds(date, 10),
+        TimeUnit::Year => month_block_bounds(date, Month::January, 12),
+        TimeUnit::Quarter => month_block_bounds(date, Month::January, 3),
+        TimeUnit::Month => month_block_bounds(date, Month::January, 1),
+        TimeUnit::Week => {
+            let start = start_of_week(date, config)?;
+            let end = start.checked_add_days(Days::new(6))
+                .ok_or(ProcessingError::AddToDate {
+                    unit: "days".to_string(),
+                    count: 6,
+                    date: NaiveDateTime::new(start, NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
+                })?;
+            Ok((start, end))
+        }
+        // A fortnight is the week containing `date` and the one after it.
+        TimeUnit::Fortnight => {
+            let start = start_of_week(date, config)?;
+            let end = start.checked_add_days(Days::new(13))
+                .ok_or(ProcessingError::AddToDate {
+                    unit: "days".to_string(),
+                    count: 13,
+                    date: NaiveDateTime::new(start, NaiveTime::MIN),
+                })?;
+            Ok((start, end))
+        }
+        TimeUnit::Season => season_containing(date, config),
+        TimeUnit::Sprint => sprint_bounds(date, config),
+        TimeUnit::BusinessDay
+        | TimeUnit::Day
+        | TimeUnit::Hour
+        | TimeUnit::BusinessHour
+        | TimeUnit::Minute
+        | TimeUnit::Second
+        | TimeUnit::Millisecond
+        | TimeUnit::Microsecond => Ok((date, date)),
     }
+}
+
+fn sprints_config(config: ParseConfig) -> Result<Sprints, ProcessingError> {
+    config.sprints
+        .filter(|sprints| sprints.length_days > 0)
+        .ok_or(ProcessingError::SprintsNotConfigured)
+}
+
+/// Returns the first and last day of the sprint containing `date`.
+fn sprint_bounds(date: NaiveDate, config: ParseConfig) -> Result<(NaiveDate, NaiveDate), ProcessingError> {
+    let Sprints { anchor, length_days } = sprints_config(config)?;
+    let length = length_days as i64;
+    let offset = (date - anchor).num_days().div_euclid(length) * length;
+
+    let start = anchor.checked_add_signed(ChronoDuration::days(offset))
+        .ok_or(ProcessingError::AddToDate {
+            unit: "days".to_string(),
+            count: offset.unsigned_abs() as u32,
+            date: NaiveDateTime::new(anchor, NaiveTime::MIN),
+        })?;
+    let end = start.checked_add_days(Days::new(length as u64 - 1))
+        .ok_or(ProcessingError::AddToDate {
+            unit: "days".to_string(),
+            count: length_days - 1,
+            date: NaiveDateTime::new(start, NaiveTime::MIN),
+        })?;
+
+    Ok((start, end))
+}
 
-    if let (TimeUnit::Week, DateTimeReference::RelativeTimeUnit(_, TimeUnit::Month)) = (time_unit, datetime_reference) {
-        return apply_ordinal_to_weeks_of_month(ordinal, base_datetime);
+fn start_of_week(date: NaiveDate, config: ParseConfig) -> Result<NaiveDate, ProcessingError> {
+    let days_from_week_start = match config.week_start_day {
+        WeekStartDay::Sunday => date.weekday().num_days_from_sunday(),
+        WeekStartDay::Monday => date.weekday().num_days_from_monday(),
+    };
+
+    date.checked_sub_days(Days::new(days_from_week_start as u64))
+        .ok_or(ProcessingError::SubtractFromDate {
+            unit: "days".to_string(),
+            count: days_from_week_start,
+            date: NaiveDateTime::new(date, NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
+        })
+}
+
+/// Returns the first and last day of the block of `length` months containing `date`. Blocks are
+/// aligned so that one of them starts in `first_month`, e.g. quarters of a fiscal year.
+fn month_block_bounds(
+    date: NaiveDate,
+    first_month: Month,
+    length: u32,
+) -> Result<(NaiveDate, NaiveDate), ProcessingError> {
+    let months_into_block = (date.month() + 12 - first_month.number_from_month()) % 12 % length;
+    let first_of_month = NaiveDate::from_ymd_opt(date.year(), date.month(), 1)
+        .ok_or(ProcessingError::InvalidDate { year: date.year(), month: date.month(), day: 1 })?;
+
+    let start = first_of_month.checked_sub_months(Months::new(months_into_block))
+        .ok_or(ProcessingError::SubtractFromDate {
+            unit: "months".to_string(),
+            count: months_into_block,
+            date: NaiveDateTime::new(first_of_month, NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
+        })?;
+    let end = start.checked_add_months(Months::new(length))
+        .and_then(|next_block| next_block.pred_opt())
+        .ok_or(ProcessingError::AddToDate {
+            unit: "months".to_string(),
+            count: length,
+            date: NaiveDateTime::new(start, NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
+        })?;
+
+    Ok((start, end))
+}
+
+/// Returns the first and last day of the block of `length` years containing `date`. Blocks start
+/// in years divisible by `length`, so the decade of 2024 is 2020 to 2029 and its century 2000 to
+/// 2099.
+fn year_block_bounds(date: NaiveDate, length: i32) -> Result<(NaiveDate, NaiveDate), ProcessingError> {
+    let first_year = date.year() - date.year().rem_euclid(length);
+    let last_year = first_year + length - 1;
+
+    let start = NaiveDate::from_ymd_opt(first_year, 1, 1)
+        .ok_or(ProcessingError::InvalidDate { year: first_year, month: 1, day: 1 })?;
+    let end = NaiveDate::from_ymd_opt(last_year, 12, 31)
+        .ok_or(ProcessingError::InvalidDate { year: last_year, month: 12, day: 31 })?;
+
+    Ok((start, end))
+}
+
+fn quarter_spec_period(spec: &QuarterSpec) -> TimeUnit {
+    match spec {
+        QuarterSpec::Quarter(_, _) => TimeUnit::Quarter,
+        QuarterSpec::FiscalQuarter(_, _) => TimeUnit::FiscalQuarter,
+        QuarterSpec::FiscalYear(_) => TimeUnit::FiscalYear,
     }
+}
+
+fn parse_quarter_spec(
+    spec: &QuarterSpec,
+    now: &NaiveDateTime,
+    config: ParseConfig,
+) -> Result<NaiveDate, ProcessingError> {
+    let (quarter, year_start, year_unit) = match spec {
+        QuarterSpec::FiscalYear(fiscal_year) => return fiscal_year_start(*fiscal_year, now, config),
+        QuarterSpec::FiscalQuarter(quarter, fiscal_year) => {
+            (*quarter, fiscal_year_start(*fiscal_year, now, config)?, TimeUnit::FiscalYear)
+        }
+        QuarterSpec::Quarter(quarter, year) => {
+            let year = year.map_or(now.year(), |year| expand_year(year, now, config));
+            let year_start = NaiveDate::from_ymd_opt(year, 1, 1)
+                .ok_or(ProcessingError::InvalidDate { year, month: 1, day: 1 })?;
+            (*quarter, year_start, TimeUnit::Year)
+        }
+    };
 
-    if let (TimeUnit::Week, DateTimeReference::MonthYear(_, _)) = (time_unit, datetime_reference) {
-        return apply_ordinal_to_weeks_of_month(ordinal, base_datetime);
+    let (start, end) = period_bounds(year_unit, year_start, config)?;
+    apply_ordinal_to_quarters(&Ordinal::Nth(quarter), start, end, quarter_spec_period(spec), year_unit)
+}
+
+/// Fiscal years are named after the calendar year they end in, so with an October start FY2025
+/// runs from October 2024 to September 2025. Two digit labels like "FY25" are expanded like other
+/// two digit years.
+fn fiscal_year_start(label: u32, now: &NaiveDateTime, config: ParseConfig) -> Result<NaiveDate, ProcessingError> {
+    let fiscal_year = expand_year(label, now, config);
+    let first_month = config.fiscal_year_start.number_from_month();
+    let year = if first_month == 1 { fiscal_year } else { fiscal_year - 1 };
+
+    NaiveDate::from_ymd_opt(year, first_month, 1)
+        .ok_or(ProcessingError::InvalidDate { year, month: first_month, day: 1 })
+}
+
+fn parse_period_boundary(
+    boundary: Boundary,
+    datetime_reference: &DateTimeReference,
+    now: &NaiveDateTime,
+    config: ParseConfig,
+) -> Result<ParseResult, ProcessingError> {
+    let base_datetime = resolve_datetime_reference(datetime_reference, now, config)?;
+    let period = reference_period(datetime_reference);
+
+    if matches!(
+        period,
+        TimeUnit::Hour
+            | TimeUnit::BusinessHour
+            | TimeUnit::Minute
+            | TimeUnit::Second
+            | TimeUnit::Millisecond
+            | TimeUnit::Microsecond
+    ) {
+        let (start, end) = subday_period_bounds(period, base_datetime);
+        return Ok(ParseResult::DateTime(match boundary {
+            Boundary::Start => start,
+            Boundary::End => end,
+        }));
     }
 
-    match time_unit {
-        TimeUnit::Day => apply_ordinal_to_days(ordinal, base_datetime),
-        TimeUnit::Week => apply_ordinal_to_weeks(ordinal, base_datetime, config),
-        TimeUnit::Month => apply_ordinal_to_months(ordinal, base_datetime),
-        TimeUnit::Year => apply_ordinal_to_years(ordinal, base_datetime),
-        TimeUnit::Hour | TimeUnit::Minute | TimeUnit::Second => {
-            apply_ordinal_to_subday_units(ordinal, time_unit, base_datetime)
+    let (start, end) = period_bounds(period, base_datetime.date(), config)?;
+    Ok(match (boundary, config.period_end) {
+        (Boundary::Start, _) => ParseResult::Date(start),
+        (Boundary::End, PeriodEnd::LastDay) => ParseResult::Date(end),
+        (Boundary::End, PeriodEnd::LastInstant) => {
+            ParseResult::DateTime(NaiveDateTime::new(end, last_instant_of_day()))
         }
+    })
+}
+
+/// Returns the first and last day of the early, middle or late third of a period. "Mid-March"
+/// is the 11th to the 20th. Every part is at least a day long, so in periods shorter than three
+/// days the parts overlap.
+fn part_of_period_bounds(
+    part: PeriodPart,
+    datetime_reference: &DateTimeReference,
+    now: &NaiveDateTime,
+    config: ParseConfig,
+) -> Result<(NaiveDate, NaiveDate), ProcessingError> {
+    let base_datetime = resolve_datetime_reference(datetime_reference, now, config)?;
+    let period = reference_period(datetime_reference);
+    if !matches!(
+        period,
+        TimeUnit::Week
+            | TimeUnit::Sprint
+            | TimeUnit::Month
+            | TimeUnit::Season
+            | TimeUnit::Quarter
+            | TimeUnit::FiscalQuarter
+            | TimeUnit::Year
+            | TimeUnit::FiscalYear
+    ) {
+        return Err(ProcessingError::UnsupportedPeriodPart { period: period.to_string() });
     }
+
+    let (start, end) = period_bounds(period, base_datetime.date(), config)?;
+    let length = (end - start).num_days() as u64 + 1;
+    let (first, last) = match part {
+        PeriodPart::Early => (0, length / 3),
+        PeriodPart::Mid => (length / 3, length * 2 / 3),
+        PeriodPart::Late => (length * 2 / 3, length),
+    };
+    let last = last.max(first + 1);
+
+    let offset_error = |offset: u64| ProcessingError::AddToDate {
+        unit: "days".to_string(),
+        count: offset as u32,
+        date: NaiveDateTime::new(start, NaiveTime::MIN),
+    };
+    let part_start = start.checked_add_days(Days::new(first)).ok_or_else(|| offset_error(first))?;
+    let part_end = start.checked_add_days(Days::new(last - 1)).ok_or_else(|| offset_error(last - 1))?;
+
+    Ok((part_start, part_end))
+}
+
+fn last_instant_of_day() -> NaiveTime {
+    NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999).unwrap()
+}
+
+/// Returns the first and last instant of the hour, minute or second containing `datetime`.
+fn subday_period_bounds(period: TimeUnit, datetime: NaiveDateTime) -> (NaiveDateTime, NaiveDateTime) {
+    let time = datetime.time();
+    let (start, length) = match period {
+        TimeUnit::Hour | TimeUnit::BusinessHour => (
+            NaiveTime::from_hms_opt(time.hour(), 0, 0),
+            ChronoDuration::hours(1),
+        ),
+        TimeUnit::Minute => (
+            NaiveTime::from_hms_opt(time.hour(), time.minute(), 0),
+            ChronoDuration::minutes(1),
+        ),
+        TimeUnit::Second => (
+            NaiveTime::from_hms_opt(time.hour(), time.minute(), time.second()),
+            ChronoDuration::seconds(1),
+        ),
+        TimeUnit::Millisecond => (
+            NaiveTime::from_hms_milli_opt(time.hour(), time.minute(), time.second(), time.nanosecond() / 1_000_000),
+            ChronoDuration::milliseconds(1),
+        ),
+        TimeUnit::Microsecond => (
+            NaiveTime::from_hms_micro_opt(time.hour(), time.minute(), time.second(), time.nanosecond() / 1_000),
+            ChronoDuration::microseconds(1),
+        ),
+        TimeUnit::FiscalYear
+        | TimeUnit::FiscalQuarter
+        | TimeUnit::Millennium
+        | TimeUnit::Century
+        | TimeUnit::Decade
+        | TimeUnit::Year
+        | TimeUnit::Quarter
+        | TimeUnit::Month
+        | TimeUnit::Season
+        | TimeUnit::Fortnight
+        | TimeUnit::Week
+        | TimeUnit::Sprint
+        | TimeUnit::BusinessDay
+        | TimeUnit::Day => {
+            unreachable!("Only sub-day time units should be used in this function.")
+        }
+    };
+
+    let start = NaiveDateTime::new(datetime.date(), start.unwrap());
+    (start, start + length - ChronoDuration::nanoseconds(1))
 }
 
 fn resolve_datetime_reference(
@@ -617,26 +2014,37 @@ fn resolve_datetime_reference(
         DateTimeReference::Now => Ok(*now),
 
         DateTimeReference::RelativeTimeUnit(relative, time_unit) => {
-            Ok(relative_date_time_unit(*relative, *time_unit, *now)?)
+            Ok(relative_date_time_unit(*relative, *time_unit, *now, config)?)
         },
 
         DateTimeReference::TheTimeUnit(_time_unit) => {
             Ok(*now)
         },
 
+        DateTimeReference::QuarterSpec(spec) => {
+            let date = parse_quarter_spec(spec, now, config)?;
+            Ok(NaiveDateTime::new(date, NaiveTime::from_hms_opt(0, 0, 0).unwrap()))
+        },
+
+        DateTimeReference::Year(year_spec) => {
+            let year = resolve_year_spec(year_spec, now, config);
+            let date = NaiveDate::from_ymd_opt(year, 1, 1)
+                .ok_or(ProcessingError::InvalidDate { year, month: 1, day: 1 })?;
+            Ok(NaiveDateTime::new(date, NaiveTime::from_hms_opt(0, 0, 0).unwrap()))
+        },
+
+        DateTimeReference::Season(spec) => {
+            let (start, _) = resolve_season_spec(spec, now, config)?;
+            Ok(NaiveDateTime::new(start, NaiveTime::from_hms_opt(0, 0, 0).unwrap()))
+        },
+
         DateTimeReference::Ago(duration) => {
-            apply_duration(duration.clone(), *now, Direction::Backwards)
+            apply_duration(duration.clone(), *now, Direction::Backwards, config)
                 .map_err(|_| ProcessingError::SubtractFromNow { unit: "duration".to_string(), count: 1 })
         },
 
         DateTimeReference::MonthYear(month_spec, year_spec) => {
-            let target_year = match year_spec {
-                None => now.year(),
-                Some(YearSpec::Relative(RelativeSpecifier::This)) => now.year(),
-                Some(YearSpec::Relative(RelativeSpecifier::Next)) => now.year() + 1,
-                Some(YearSpec::Relative(RelativeSpecifier::Last)) => now.year() - 1,
-                Some(YearSpec::Absolute(year)) => *year as i32,
-            };
+            let target_year = year_spec.as_ref().map_or(now.year(), |year_spec| resolve_year_spec(year_spec, now, config));
 
             let (target_month, final_year) = match month_spec {
                 MonthSpec::Absolute(month) => (month.number_from_month(), target_year),
@@ -665,7 +2073,14 @@ fn resolve_datetime_reference(
                     let month_num = month.number_from_month();
                     match relative {
                         RelativeSpecifier::This => (month_num, target_year),
-                        RelativeSpecifier::Next => (month_num, target_year + 1),
+                        // The same as "next April" on its own, see `resolve_month_of_year`.
+                        RelativeSpecifier::Next => {
+                            if now.month() >= month_num {
+                                (month_num, target_year + 1)
+                            } else {
+                                (month_num, target_year)
+                            }
+                        }
                         RelativeSpecifier::Last => {
                             if now.month() <= month_num {
                                 (month_num, target_year - 1)
@@ -686,145 +2101,175 @@ fn resolve_datetime_reference(
     }
 }
 
-fn apply_ordinal_to_days(ordinal: &Ordinal, base_datetime: NaiveDateTime) -> Result<NaiveDate, ProcessingError> {
-    let base_date = base_datetime.date();
-    let target_day = match ordinal {
-        Ordinal::First => 1,
-        Ordinal::Last => {
-            let next_month = if base_date.month() == 12 {
-                NaiveDate::from_ymd_opt(base_date.year() + 1, 1, 1)
-            } else {
-                NaiveDate::from_ymd_opt(base_date.year(), base_date.month() + 1, 1)
-            };
-            match next_month {
-                Some(date) => (date - Days::new(1)).day(),
-                None => return Err(ProcessingError::InvalidDate {
-                    year: base_date.year(), month: base_date.month(), day: 1
-                })
-            }
-        },
-        Ordinal::Nth(n) => *n,
-    };
+fn resolve_year_spec(year_spec: &YearSpec, now: &NaiveDateTime, config: ParseConfig) -> i32 {
+    match year_spec {
+        YearSpec::Relative(RelativeSpecifier::This) => now.year(),
+        YearSpec::Relative(RelativeSpecifier::Next) => now.year() + 1,
+        YearSpec::Relative(RelativeSpecifier::Last) => now.year() - 1,
+        YearSpec::Absolute(year) => expand_year(*year, now, config),
+    }
+}
 
-    NaiveDate::from_ymd_opt(base_date.year(), base_date.month(), target_day)
-        .ok_or(ProcessingError::InvalidDate {
-            year: base_date.year(),
-            month: base_date.month(),
-            day: target_day,
-        })
+/// Expands years below 100, like the "15" in "7 Feb 15", as set by `ParseConfig::two_digit_years`.
+fn expand_year(year: u32, now: &NaiveDateTime, config: ParseConfig) -> i32 {
+    let year = year as i32;
+    if year >= 100 {
+        return year;
+    }
+
+    match config.two_digit_years {
+        TwoDigitYears::Sliding { years_ahead } => {
+            let latest = now.year().saturating_add(i32::try_from(years_ahead).unwrap_or(i32::MAX));
+            let last_digits = latest.rem_euclid(100);
+            // Never goes past `latest`, so this can't overflow.
+            latest - last_digits + year - if year > last_digits { 100 } else { 0 }
+        }
+        TwoDigitYears::Fixed { pivot } if year < pivot as i32 => 2000 + year,
+        TwoDigitYears::Fixed { .. } => 1900 + year,
+        TwoDigitYears::Literal => year,
+    }
 }
 
-fn apply_ordinal_to_weeks(ordinal: &Ordinal, base_datetime: NaiveDateTime, config: ParseConfig) -> Result<NaiveDate, ProcessingError> {
-    let base_date = base_datetime.date();
+fn apply_ordinal_to_days(
+    ordinal: &Ordinal,
+    start: NaiveDate,
+    end: NaiveDate,
+    period: TimeUnit,
+) -> Result<NaiveDate, ProcessingError> {
+    apply_ordinal_to_blocks(ordinal, start, end, 1, TimeUnit::Day, period)
+}
 
-    let days_from_week_start = match config.week_start_day {
-        WeekStartDay::Sunday => base_date.weekday().num_days_from_sunday() as i64,
-        WeekStartDay::Monday => base_date.weekday().num_days_from_monday() as i64,
+fn apply_ordinal_to_business_days(
+    ordinal: &Ordinal,
+    start: NaiveDate,
+    end: NaiveDate,
+    period: TimeUnit,
+    config: ParseConfig,
+) -> Result<NaiveDate, ProcessingError> {
+    let mut business_days = start
+        .iter_days()
+        .take_while(|date| *date <= end)
+        .filter(|date| is_business_day(*date, config));
+
+    let target = match ordinal {
+        Ordinal::First => business_days.next(),
+        Ordinal::Last => business_days.last(),
+        Ordinal::Nth(n) => n.checked_sub(1).and_then(|n| business_days.nth(n as usize)),
     };
 
-    let week_start = base_date.checked_sub_days(Days::new(days_from_week_start as u64))
-        .ok_or(ProcessingError::SubtractFromNow {
-            unit: "days".to_string(),
-            count: days_from_week_start as u32
-        })?;
-
-    match ordinal {
-        Ordinal::First => {
-            Ok(week_start)
-        },
-        Ordinal::Last => {
-            Ok(week_start.checked_add_days(Days::new(6))
-                .ok_or(ProcessingError::AddToDate {
-                    unit: "days".to_string(),
-                    count: 6,
-                    date: NaiveDateTime::new(week_start, NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
-                })?)
+    target.ok_or(ProcessingError::OrdinalOutOfRange {
+        ordinal: match ordinal {
+            Ordinal::Nth(n) => *n,
+            _ => 1,
         },
-        Ordinal::Nth(n) => {
-            if *n < 1 || *n > 7 {
-                return Err(ProcessingError::InvalidDate {
-                    year: base_date.year(),
-                    month: base_date.month(),
-                    day: *n
-                });
-            }
-            Ok(week_start.checked_add_days(Days::new((*n - 1) as u64))
-                .ok_or(ProcessingError::AddToDate {
-                    unit: "days".to_string(),
-                    count: *n - 1,
-                    date: NaiveDateTime::new(week_start, NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
-                })?)
-        }
-    }
+        unit: TimeUnit::BusinessDay.to_string(),
+        period: period.to_string(),
+    })
 }
 
-fn apply_ordinal_to_months(ordinal: &Ordinal, base_datetime: NaiveDateTime) -> Result<NaiveDate, ProcessingError> {
-    let base_date = base_datetime.date();
-    let target_month = match ordinal {
-        Ordinal::First => 1,
-        Ordinal::Last => 12,
-        Ordinal::Nth(n) => *n,
-    };
+/// Weeks within a period are blocks of seven days from its first day, as "2nd week of next
+/// month" always was. They don't follow `ParseConfig::week_start_day`, and the last one can be
+/// shorter: the last week of 2010 is just December 31st.
+fn apply_ordinal_to_weeks(
+    ordinal: &Ordinal,
+    start: NaiveDate,
+    end: NaiveDate,
+    period: TimeUnit,
+) -> Result<NaiveDate, ProcessingError> {
+    apply_ordinal_to_blocks(ordinal, start, end, 7, TimeUnit::Week, period)
+}
 
-    if target_month < 1 || target_month > 12 {
-        return Err(ProcessingError::InvalidDate {
-            year: base_date.year(),
-            month: target_month,
-            day: 1
-        });
-    }
+/// Returns the first day of the nth block of `length` days counted from `start`. The last block is
+/// the last one that starts in the period, so "last" is always the highest valid n.
+fn apply_ordinal_to_blocks(
+    ordinal: &Ordinal,
+    start: NaiveDate,
+    end: NaiveDate,
+    length: u64,
+    unit: TimeUnit,
+    period: TimeUnit,
+) -> Result<NaiveDate, ProcessingError> {
+    let period_length = (end - start).num_days() as u64 + 1;
+    let offset = match ordinal {
+        Ordinal::First => 0,
+        Ordinal::Last => (period_length - 1) / length * length,
+        Ordinal::Nth(n) if *n >= 1 && (*n as u64 - 1) * length < period_length => {
+            (*n as u64 - 1) * length
+        }
+        Ordinal::Nth(n) => {
+            return Err(ProcessingError::OrdinalOutOfRange {
+                ordinal: *n,
+                unit: unit.to_string(),
+                period: period.to_string(),
+            })
+        }
+    };
 
-    NaiveDate::from_ymd_opt(base_date.year(), target_month, 1)
-        .ok_or(ProcessingError::InvalidDate {
-            year: base_date.year(),
-            month: target_month,
-            day: 1,
+    start.checked_add_days(Days::new(offset))
+        .ok_or(ProcessingError::AddToDate {
+            unit: "days".to_string(),
+            count: offset as u32,
+            date: NaiveDateTime::new(start, NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
         })
 }
 
-fn apply_ordinal_to_years(ordinal: &Ordinal, base_datetime: NaiveDateTime) -> Result<NaiveDate, ProcessingError> {
-    let base_date = base_datetime.date();
-    let target_year = base_date.year();
+fn apply_ordinal_to_months(
+    ordinal: &Ordinal,
+    start: NaiveDate,
+    end: NaiveDate,
+    period: TimeUnit,
+) -> Result<NaiveDate, ProcessingError> {
+    apply_ordinal_to_month_blocks(ordinal, start, end, 1, TimeUnit::Month, period)
+}
 
-    match ordinal {
-        Ordinal::First => {
-            NaiveDate::from_ymd_opt(target_year, 1, 1)
-                .ok_or(ProcessingError::InvalidDate {
-                    year: target_year,
-                    month: 1,
-                    day: 1,
-                })
-        },
-        Ordinal::Last => {
-            NaiveDate::from_ymd_opt(target_year, 12, 31)
-                .ok_or(ProcessingError::InvalidDate {
-                    year: target_year,
-                    month: 12,
-                    day: 31,
-                })
-        },
-        Ordinal::Nth(n) => {
-            let jan_1 = NaiveDate::from_ymd_opt(target_year, 1, 1)
-                .ok_or(ProcessingError::InvalidDate {
-                    year: target_year,
-                    month: 1,
-                    day: 1,
-                })?;
+fn apply_ordinal_to_quarters(
+    ordinal: &Ordinal,
+    start: NaiveDate,
+    end: NaiveDate,
+    unit: TimeUnit,
+    period: TimeUnit,
+) -> Result<NaiveDate, ProcessingError> {
+    apply_ordinal_to_month_blocks(ordinal, start, end, 3, unit, period)
+}
 
-            jan_1.checked_add_days(Days::new((*n - 1) as u64))
-                .ok_or(ProcessingError::AddToDate {
-                    unit: "days".to_string(),
-                    count: *n,
-                    date: NaiveDateTime::new(jan_1, NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
-                })
+/// Like `apply_ordinal_to_blocks`, but for blocks of `length` months. `start` has to be the first
+/// day of a month.
+fn apply_ordinal_to_month_blocks(
+    ordinal: &Ordinal,
+    start: NaiveDate,
+    end: NaiveDate,
+    length: u32,
+    unit: TimeUnit,
+    period: TimeUnit,
+) -> Result<NaiveDate, ProcessingError> {
+    let period_length = (end.year() - start.year()) as u32 * 12 + end.month() + 1 - start.month();
+    let offset = match ordinal {
+        Ordinal::First => 0,
+        Ordinal::Last => (period_length - 1) / length * length,
+        Ordinal::Nth(n) if *n >= 1 && (*n as u64 - 1) * (length as u64) < period_length as u64 => {
+            (*n - 1) * length
         }
-    }
+        Ordinal::Nth(n) => {
+            return Err(ProcessingError::OrdinalOutOfRange {
+                ordinal: *n,
+                unit: unit.to_string(),
+                period: period.to_string(),
+            })
+        }
+    };
+
+    start.checked_add_months(Months::new(offset))
+        .ok_or(ProcessingError::AddToDate {
+            unit: "months".to_string(),
+            count: offset,
+            date: NaiveDateTime::new(start, NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
+        })
 }
 
-fn parse_month_duration_from_now(month: &Month, duration: &AstDuration, now: &NaiveDateTime) -> Result<NaiveDate, ProcessingError> {
+fn parse_month_duration_from_now(month: &Month, duration: &AstDuration, now: &NaiveDateTime, config: ParseConfig) -> Result<NaiveDate, ProcessingError> {
     // This is like "april 2 years from now"
     // First apply the duration to get the target year, then get the first day of that month in that year
-    let target_datetime = apply_duration(duration.clone(), *now, Direction::Forwards)?;
+    let target_datetime = apply_duration(duration.clone(), *now, Direction::Forwards, config)?;
     let target_year = target_datetime.year();
     let target_month = month.number_from_month();
     
@@ -836,10 +2281,10 @@ fn parse_month_duration_from_now(month: &Month, duration: &AstDuration, now: &Na
         })
 }
 
-fn parse_month_duration_ago(month: &Month, duration: &AstDuration, now: &NaiveDateTime) -> Result<NaiveDate, ProcessingError> {
+fn parse_month_duration_ago(month: &Month, duration: &AstDuration, now: &NaiveDateTime, config: ParseConfig) -> Result<NaiveDate, ProcessingError> {
     // This is like "december 3 years ago"  
     // First apply the duration backwards to get the target year, then get the first day of that month in that year
-    let target_datetime = apply_duration(duration.clone(), *now, Direction::Backwards)?;
+    let target_datetime = apply_duration(duration.clone(), *now, Direction::Backwards, config)?;
     let target_year = target_datetime.year();
     let target_month = month.number_from_month();
     
@@ -851,41 +2296,52 @@ fn parse_month_duration_ago(month: &Month, duration: &AstDuration, now: &NaiveDa
         })
 }
 
-fn apply_ordinal_to_weeks_of_month(ordinal: &Ordinal, base_datetime: NaiveDateTime) -> Result<NaiveDate, ProcessingError> {
-    let base_date = base_datetime.date();
-    let first_of_month = NaiveDate::from_ymd_opt(base_date.year(), base_date.month(), 1)
-        .ok_or(ProcessingError::InvalidDate { year: base_date.year(), month: base_date.month(), day: 1 })?;
-
-    let week_number = match ordinal {
-        Ordinal::First => 1,
-        Ordinal::Last => {
-            let last_day = if base_date.month() == 12 {
-                NaiveDate::from_ymd_opt(base_date.year() + 1, 1, 1).unwrap() - Days::new(1)
-            } else {
-                NaiveDate::from_ymd_opt(base_date.year(), base_date.month() + 1, 1).unwrap() - Days::new(1)
-            };
-            ((last_day.day() - 1) / 7) + 1
-        },
+fn apply_ordinal_to_subday_units(
+    ordinal: &Ordinal,
+    time_unit: &TimeUnit,
+    start: NaiveDate,
+    end: NaiveDate,
+    period: TimeUnit,
+) -> Result<NaiveDate, ProcessingError> {
+    let n = match ordinal {
+        Ordinal::First => return Ok(start),
+        Ordinal::Last => return Ok(end),
         Ordinal::Nth(n) => *n,
     };
 
-    let target_date = first_of_month + Days::new(((week_number - 1) * 7) as u64);
+    let offset = n.saturating_sub(1) as i64;
+    let offset = match time_unit {
+        TimeUnit::Hour => ChronoDuration::hours(offset),
+        TimeUnit::Minute => ChronoDuration::minutes(offset),
+        TimeUnit::Second => ChronoDuration::seconds(offset),
+        TimeUnit::Millisecond => ChronoDuration::milliseconds(offset),
+        TimeUnit::Microsecond => ChronoDuration::microseconds(offset),
+        TimeUnit::FiscalYear
+        | TimeUnit::FiscalQuarter
+        | TimeUnit::Millennium
+        | TimeUnit::Century
+        | TimeUnit::Decade
+        | TimeUnit::Year
+        | TimeUnit::Quarter
+        | TimeUnit::Month
+        | TimeUnit::Season
+        | TimeUnit::Fortnight
+        | TimeUnit::Week
+        | TimeUnit::Sprint
+        | TimeUnit::BusinessDay
+        | TimeUnit::Day
+        | TimeUnit::BusinessHour => {
+            unreachable!("Only sub-day time units should be used in this function.")
+        }
+    };
 
-    if target_date.month() == base_date.month() {
-        Ok(target_date)
-    } else {
-        Err(ProcessingError::InvalidDate {
-            year: base_date.year(),
-            month: base_date.month(),
-            day: target_date.day()
+    NaiveDateTime::new(start, NaiveTime::from_hms_opt(0, 0, 0).unwrap())
+        .checked_add_signed(offset)
+        .map(|target| target.date())
+        .filter(|target| n >= 1 && *target <= end)
+        .ok_or(ProcessingError::OrdinalOutOfRange {
+            ordinal: n,
+            unit: time_unit.to_string(),
+            period: period.to_string(),
         })
-    }
-}
-
-fn apply_ordinal_to_subday_units(
-    _ordinal: &Ordinal,
-    _time_unit: &TimeUnit,
-    base_datetime: NaiveDateTime,
-) -> Result<NaiveDate, ProcessingError> {
-    Ok(base_datetime.date())
 }
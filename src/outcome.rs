@@ -0,0 +1,214 @@
+use std::ops::Range;
+
+use chrono::NaiveDateTime;
+
+use crate::{
+    ast::{
+        Ago, Date, DateTimeReference, HumanTime, MonthOfYear, MonthSpec, Quantifier, QuarterSpec,
+        SeasonSpec, Time, YearSpec,
+    },
+    ParseConfig, ParseResult, TimeFill, TwoDigitYears,
+};
+
+/// A parsed value along with what is known about it, returned by
+/// [`from_human_time_detailed`](crate::from_human_time_detailed).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOutcome {
+    pub value: ParseResult,
+    /// The value as a datetime, with the time of day of dates filled in by
+    /// `ParseConfig::time_fill`.
+    pub datetime: Option<NaiveDateTime>,
+    pub precision: Precision,
+    /// Whether the value depends on `now`, like "tomorrow" or "15:00", as opposed to "2024-01-01".
+    /// Two-digit years in a `TwoDigitYears::Sliding` window count as relative.
+    pub relative: bool,
+    /// Where the value lies relative to `now`. Values spanning `now`, like "today", are present.
+    pub tense: Tense,
+    /// The byte range of the lowercased input that was parsed. Anything after it was ignored.
+    pub span: Range<usize>,
+}
+
+/// The smallest unit a value was given in. "2024" has year precision, "3 days ago" day precision
+/// and "15:00" minute precision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Precision {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Millisecond,
+    Microsecond,
+    Nanosecond,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tense {
+    Past,
+    Present,
+    Future,
+}
+
+/// Returns the precision of `value`. Times get the `time_precision` of the expression they were
+/// evaluated from.
+pub(crate) fn precision(value: &ParseResult, time_precision: Precision) -> Precision {
+    match value {
+        ParseResult::Year(_) => Precision::Year,
+        ParseResult::YearMonth(_, _) => Precision::Month,
+        ParseResult::Date(_) | ParseResult::DateRange(_, _) => Precision::Day,
+        ParseResult::Time(_) | ParseResult::DateTime(_) => time_precision,
+    }
+}
+
+/// Returns the precision of `human_time`, if it evaluates to a time.
+pub(crate) fn human_time_precision(human_time: &HumanTime) -> Precision {
+    match human_time {
+        HumanTime::DateTime(date_time) => time_precision(&date_time.time),
+        HumanTime::Time(time) => time_precision(time),
+        HumanTime::Date(_) => Precision::Day,
+        HumanTime::In(in_ast) => duration_precision(&in_ast.0 .0),
+        HumanTime::Ago(Ago::AgoFromNow(duration)) => duration_precision(&duration.0),
+        HumanTime::Ago(Ago::AgoFromTime(duration, human_time)) => {
+            duration_precision(&duration.0).max(human_time_precision(human_time))
+        }
+        HumanTime::BusinessHoursBoundary(_, _) => Precision::Minute,
+        HumanTime::PeriodBoundary(_, _) | HumanTime::Now => Precision::Second,
+    }
+}
+
+fn time_precision(time: &Time) -> Precision {
+    match time {
+        Time::HourMinute(_, _) => Precision::Minute,
+        Time::HourMinuteSecond(_, _, _) => Precision::Second,
+        Time::Fractional(_, _, _, nano) if nano % 1_000_000 == 0 => Precision::Millisecond,
+        Time::Fractional(_, _, _, nano) if nano % 1_000 == 0 => Precision::Microsecond,
+        Time::Fractional(_, _, _, _) => Precision::Nanosecond,
+    }
+}
+
+fn duration_precision(quantifiers: &[Quantifier]) -> Precision {
+    quantifiers
+        .iter()
+        .map(|quantifier| match quantifier {
+            Quantifier::Year(_) => Precision::Year,
+            Quantifier::Quarter(_) | Quantifier::Month(_) => Precision::Month,
+            Quantifier::Week(_) | Quantifier::Sprint(_) | Quantifier::BusinessDay(_) | Quantifier::Day(_) => {
+                Precision::Day
+            }
+            Quantifier::BusinessHour(_) | Quantifier::Hour(_) => Precision::Hour,
+            Quantifier::Minute(_) => Precision::Minute,
+            Quantifier::Second(_) => Precision::Second,
+            Quantifier::Millisecond(_) => Precision::Millisecond,
+            Quantifier::Microsecond(_) => Precision::Microsecond,
+        })
+        .max()
+        .unwrap_or(Precision::Day)
+}
+
+/// Whether the value of `human_time` changes with `now`.
+pub(crate) fn is_relative(human_time: &HumanTime, config: ParseConfig) -> bool {
+    match human_time {
+        HumanTime::DateTime(date_time) => date_is_relative(&date_time.date, config),
+        HumanTime::Date(date) => date_is_relative(date, config),
+        HumanTime::PeriodBoundary(_, datetime_reference) => reference_is_relative(datetime_reference, config),
+        HumanTime::BusinessHoursBoundary(_, Some(date)) => date_is_relative(date, config),
+        HumanTime::BusinessHoursBoundary(_, None)
+        | HumanTime::Time(_)
+        | HumanTime::In(_)
+        | HumanTime::Ago(_)
+        | HumanTime::Now => true,
+    }
+}
+
+fn date_is_relative(date: &Date, config: ParseConfig) -> bool {
+    match date {
+        Date::IsoDate(_) | Date::IsoWeekDate(_) | Date::Year(_) => false,
+        Date::MonthOfYear(MonthOfYear::Absolute(_, _)) => false,
+        Date::WeekNumber(_, year_spec)
+        | Date::DayOfYear(_, year_spec)
+        | Date::DayMonth(_, _, year_spec)
+        | Date::Holiday(_, year_spec) => year_is_relative(year_spec.as_ref(), config),
+        Date::OrdinalTimeUnitOf(_, _, datetime_reference) | Date::PartOfPeriod(_, datetime_reference) => {
+            reference_is_relative(datetime_reference, config)
+        }
+        Date::DurationRelativeTo(_, _, date) | Date::WeekdayRelativeTo(_, _, date) => date_is_relative(date, config),
+        Date::QuarterSpec(spec) => quarter_is_relative(spec, config),
+        Date::Season(spec) => season_is_relative(spec, config),
+        Date::Today
+        | Date::Tomorrow
+        | Date::Overmorrow
+        | Date::Yesterday
+        | Date::DayOfMonth(_)
+        | Date::MonthOfYear(MonthOfYear::Relative(_, _))
+        | Date::RelativeWeekWeekday(_, _)
+        | Date::RelativeTimeUnit(_, _)
+        | Date::RelativeWeekday(_, _)
+        | Date::UpcomingWeekday(_)
+        | Date::MonthDurationFromNow(_, _)
+        | Date::MonthDurationAgo(_, _)
+        | Date::RelativeHoliday(_, _)
+        | Date::Weekend(_)
+        | Date::EveryWeekday => true,
+    }
+}
+
+fn reference_is_relative(datetime_reference: &DateTimeReference, config: ParseConfig) -> bool {
+    match datetime_reference {
+        DateTimeReference::MonthYear(MonthSpec::Absolute(_), year_spec) => {
+            year_is_relative(year_spec.as_ref(), config)
+        }
+        DateTimeReference::Year(year_spec) => year_is_relative(Some(year_spec), config),
+        DateTimeReference::QuarterSpec(spec) => quarter_is_relative(spec, config),
+        DateTimeReference::Season(spec) => season_is_relative(spec, config),
+        DateTimeReference::MonthYear(_, _)
+        | DateTimeReference::Ago(_)
+        | DateTimeReference::RelativeTimeUnit(_, _)
+        | DateTimeReference::TheTimeUnit(_)
+        | DateTimeReference::Today
+        | DateTimeReference::Tomorrow
+        | DateTimeReference::Yesterday
+        | DateTimeReference::Overmorrow
+        | DateTimeReference::Now => true,
+    }
+}
+
+fn year_is_relative(year_spec: Option<&YearSpec>, config: ParseConfig) -> bool {
+    match year_spec {
+        Some(YearSpec::Absolute(year)) => is_sliding_year(*year, config),
+        Some(YearSpec::Relative(_)) | None => true,
+    }
+}
+
+/// Whether `year` is a two-digit year expanded into a window that moves with `now`.
+fn is_sliding_year(year: u32, config: ParseConfig) -> bool {
+    year < 100 && matches!(config.two_digit_years, TwoDigitYears::Sliding { .. })
+}
+
+fn quarter_is_relative(spec: &QuarterSpec, config: ParseConfig) -> bool {
+    match spec {
+        QuarterSpec::Quarter(_, None) => true,
+        QuarterSpec::Quarter(_, Some(year)) | QuarterSpec::FiscalQuarter(_, year) | QuarterSpec::FiscalYear(year) => {
+            is_sliding_year(*year, config)
+        }
+    }
+}
+
+fn season_is_relative(spec: &SeasonSpec, config: ParseConfig) -> bool {
+    match spec {
+        SeasonSpec::Relative(_, _) => true,
+        SeasonSpec::Year(_, year_spec) => year_is_relative(year_spec.as_ref(), config),
+    }
+}
+
+/// Compares the whole span of `value` to `now`.
+pub(crate) fn tense(value: &ParseResult, now: NaiveDateTime) -> Tense {
+    let start = value.to_datetime(now, TimeFill::StartOfDay);
+    let end = value.to_datetime(now, TimeFill::EndOfDay);
+
+    match (start, end) {
+        (Some(start), _) if start > now => Tense::Future,
+        (_, Some(end)) if end < now => Tense::Past,
+        _ => Tense::Present,
+    }
+}
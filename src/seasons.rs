@@ -0,0 +1,167 @@
+use chrono::{Datelike, Days, NaiveDate, NaiveDateTime};
+
+use crate::{
+    ast::{RelativeSpecifier, Season, SeasonSpec},
+    resolve_year_spec, Hemisphere, ParseConfig, ProcessingError, SeasonBoundaries,
+};
+
+/// Resolves a season like "next summer" or "winter 2024" to its first and last day. Seasons are
+/// named by the year they start in, so "winter 2024" starts in December 2024 in the northern
+/// hemisphere.
+pub(crate) fn resolve_season_spec(
+    spec: &SeasonSpec,
+    now: &NaiveDateTime,
+    config: ParseConfig,
+) -> Result<(NaiveDate, NaiveDate), ProcessingError> {
+    let today = now.date();
+    let (relative, season) = match spec {
+        SeasonSpec::Year(season, Some(year_spec)) => {
+            return season_bounds(*season, resolve_year_spec(year_spec, now, config), config)
+        }
+        SeasonSpec::Year(season, None) => (RelativeSpecifier::This, *season),
+        SeasonSpec::Relative(relative, season) => (*relative, *season),
+    };
+
+    // A season that ended before today may have started two years ago, e.g. last winter in January.
+    // Years without valid dates, at the ends of the supported range, are only an error if the
+    // season would have been in them.
+    let candidates: Vec<_> = (today.year() - 2..=today.year() + 1)
+        .map(|year| season_bounds(season, year, config))
+        .collect();
+    let valid = || candidates.iter().flatten();
+
+    let current = valid().find(|(start, end)| (*start..=*end).contains(&today));
+    let upcoming = valid().find(|(start, _)| *start > today);
+    let previous = valid().rev().find(|(_, end)| *end < today);
+
+    let bounds = match relative {
+        RelativeSpecifier::This => current.or(upcoming),
+        RelativeSpecifier::Next => upcoming,
+        RelativeSpecifier::Last => previous,
+    };
+
+    match bounds {
+        Some(bounds) => Ok(*bounds),
+        None => Err(candidates
+            .into_iter()
+            .find_map(Result::err)
+            .expect("Four years of seasons contain the current, next and last one")),
+    }
+}
+
+/// Returns the first and last day of the season containing `date`.
+pub(crate) fn season_containing(
+    date: NaiveDate,
+    config: ParseConfig,
+) -> Result<(NaiveDate, NaiveDate), ProcessingError> {
+    for year in [date.year() - 1, date.year()] {
+        for season in [Season::Spring, Season::Summer, Season::Autumn, Season::Winter] {
+            let (start, end) = season_bounds(season, year, config)?;
+            if (start..=end).contains(&date) {
+                return Ok((start, end));
+            }
+        }
+    }
+
+    unreachable!("Seasons cover the whole year")
+}
+
+/// Returns the first and last day of `season` starting in `year`.
+fn season_bounds(
+    season: Season,
+    year: i32,
+    config: ParseConfig,
+) -> Result<(NaiveDate, NaiveDate), ProcessingError> {
+    // Seasons are numbered from the one starting in March, which is spring in the north.
+    let index = match (season, config.hemisphere) {
+        (Season::Spring, Hemisphere::Northern) | (Season::Autumn, Hemisphere::Southern) => 0,
+        (Season::Summer, Hemisphere::Northern) | (Season::Winter, Hemisphere::Southern) => 1,
+        (Season::Autumn, Hemisphere::Northern) | (Season::Spring, Hemisphere::Southern) => 2,
+        (Season::Winter, Hemisphere::Northern) | (Season::Summer, Hemisphere::Southern) => 3,
+    };
+
+    let start = season_start(index, year, config)?;
+    let end = if index == 3 {
+        season_start(0, year + 1, config)?
+    } else {
+        season_start(index + 1, year, config)?
+    };
+    let end = end.pred_opt().ok_or(ProcessingError::InvalidDate {
+        year,
+        month: end.month(),
+        day: end.day(),
+    })?;
+
+    Ok((start, end))
+}
+
+fn season_start(index: usize, year: i32, config: ParseConfig) -> Result<NaiveDate, ProcessingError> {
+    let month = 3 + 3 * index as u32;
+    let start = match config.seasons {
+        SeasonBoundaries::Meteorological => NaiveDate::from_ymd_opt(year, month, 1),
+        SeasonBoundaries::Astronomical => equinox_or_solstice(index, year),
+    };
+
+    start.ok_or(ProcessingError::InvalidDate { year, month, day: 1 })
+}
+
+/// Periodic terms of the equinox and solstice correction, from Jean Meeus, Astronomical
+/// Algorithms, table 27.C.
+const PERIODIC_TERMS: [(f64, f64, f64); 24] = [
+    (485.0, 324.96, 1934.136),
+    (203.0, 337.23, 32964.467),
+    (199.0, 342.08, 20.186),
+    (182.0, 27.85, 445267.112),
+    (156.0, 73.14, 45036.886),
+    (136.0, 171.52, 22518.443),
+    (77.0, 222.54, 65928.934),
+    (74.0, 296.72, 3034.906),
+    (70.0, 243.58, 9037.513),
+    (58.0, 119.81, 33718.147),
+    (52.0, 297.17, 150.678),
+    (50.0, 21.02, 2281.226),
+    (45.0, 247.54, 29929.562),
+    (44.0, 325.15, 31555.956),
+    (29.0, 60.93, 4443.417),
+    (18.0, 155.12, 67555.328),
+    (17.0, 288.79, 4562.452),
+    (16.0, 198.04, 62894.029),
+    (14.0, 199.76, 31436.921),
+    (12.0, 95.39, 14577.848),
+    (12.0, 287.11, 31931.756),
+    (12.0, 320.81, 34777.259),
+    (9.0, 227.73, 1222.114),
+    (8.0, 15.45, 16859.074),
+];
+
+/// Returns the UTC date of the March equinox (0), June solstice (1), September equinox (2) or
+/// December solstice (3) of `year`, using the method from Astronomical Algorithms, chapter 27. It
+/// is accurate to within minutes for the years 1000 to 3000.
+fn equinox_or_solstice(index: usize, year: i32) -> Option<NaiveDate> {
+    let y = (year as f64 - 2000.0) / 1000.0;
+    let mean = match index {
+        0 => [2451623.80984, 365242.37404, 0.05169, -0.00411, -0.00057],
+        1 => [2451716.56767, 365241.62603, 0.00325, 0.00888, -0.00030],
+        2 => [2451810.21715, 365242.01767, -0.11575, 0.00337, 0.00078],
+        _ => [2451900.05952, 365242.74049, -0.06223, -0.00823, 0.00032],
+    };
+    let jde0 = mean.iter().rev().fold(0.0, |acc, coefficient| acc * y + coefficient);
+
+    let t = (jde0 - 2451545.0) / 36525.0;
+    let w = (35999.373 * t - 2.47).to_radians();
+    let delta_lambda = 1.0 + 0.0334 * w.cos() + 0.0007 * (2.0 * w).cos();
+    let s: f64 = PERIODIC_TERMS
+        .iter()
+        .map(|(a, b, c)| a * (b + c * t).to_radians().cos())
+        .sum();
+    let jde = jde0 + 0.00001 * s / delta_lambda;
+
+    // Julian day 2451544.5 is the start of January 1st, 2000.
+    let days = (jde - 2451544.5).floor() as i64;
+    let epoch = NaiveDate::from_ymd_opt(2000, 1, 1)?;
+    if days.is_negative() {
+        epoch.checked_sub_days(Days::new(days.unsigned_abs()))
+    } else {
+        epoch.checked_add_days(Days::new(days as u64))
+    }
+}
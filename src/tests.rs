@@ -1,4 +1,4 @@
-#[allow(non_snake_case)]
+#![allow(non_snake_case)]
 use super::*;
 use crate::ast::DateTimeParser;
 use crate::ast::Rule;
@@ -26,13 +26,9 @@ macro_rules! generate_test_cases {
                         let input = $case.to_lowercase();
                         let now = NaiveDateTime::new(NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(), NaiveTime::from_hms_opt(0, 0, 0).unwrap());
                         let result = from_human_time(&input, now).unwrap();
-                        let expected = NaiveDateTime::parse_from_str( $expected , "%Y-%m-%d %H:%M:%S").unwrap();
+                        let expected = NaiveDateTime::parse_from_str( $expected , "%Y-%m-%d %H:%M:%S%.f").unwrap();
 
-                        let result = match result {
-                            ParseResult::DateTime(datetime) => datetime,
-                            ParseResult::Date(date) => NaiveDateTime::new(date, now.time()),
-                            ParseResult::Time(time) => NaiveDateTime::new(now.date(), time),
-                        };
+                        let result = result.to_datetime(now, TimeFill::Now).unwrap();
 
                         println!("Result: {result}\nExpected: {expected}\nNote: Maximum difference between these values allowed is 10ms.");
                         assert!((result - expected).abs() < chrono::Duration::milliseconds(10));
@@ -133,7 +129,7 @@ generate_test_cases!(
     "first day of april" = "2010-04-01 00:00:00",
     "last day of february" = "2010-02-28 00:00:00",
     "15th day of july" = "2010-07-15 00:00:00",
-    "first day of next april" = "2011-04-01 00:00:00",
+    "first day of next april" = "2010-04-01 00:00:00",
     "last day of last december" = "2009-12-31 00:00:00",
     "first day of april next year" = "2011-04-01 00:00:00",
     "last day of march last year" = "2009-03-31 00:00:00",
@@ -147,7 +143,7 @@ generate_test_cases!(
     "first day of next month" = "2010-02-01 00:00:00",
     "31st day of last month" = "2009-12-31 00:00:00",
     "2nd day of 2 months ago" = "2009-11-02 00:00:00",
-    "3rd day of next week" = "2010-01-03 00:00:00",
+    "3rd day of next week" = "2010-01-05 00:00:00",
     "1st hour of tomorrow" = "2010-01-02 00:00:00",
     "last day of next year" = "2011-12-31 00:00:00",
     "last day of last year" = "2009-12-31 00:00:00",
@@ -170,7 +166,210 @@ generate_test_cases!(
     "three months ago" = "2009-10-01 00:00:00",
     "april 2 years from now" = "2012-04-01 00:00:00",
     "december 3 years ago" = "2007-12-01 00:00:00",
-    "january five years from now" = "2015-01-01 00:00:00"
+    "january five years from now" = "2015-01-01 00:00:00",
+    "third month of next year" = "2011-03-01 00:00:00",
+    "last month of last year" = "2009-12-01 00:00:00",
+    "first month of the year" = "2010-01-01 00:00:00",
+    "last week of the year" = "2010-12-31 00:00:00",
+    "last week of last year" = "2009-12-31 00:00:00",
+    "first week of next year" = "2011-01-01 00:00:00",
+    "2nd week of the year" = "2010-01-08 00:00:00",
+    "53rd week of the year" = "2010-12-31 00:00:00",
+    "last week of december" = "2010-12-29 00:00:00",
+    "2nd week of last month" = "2009-12-08 00:00:00",
+    "last week of the month" = "2010-01-29 00:00:00",
+    "5th week of the month" = "2010-01-29 00:00:00",
+    "first day of this week" = "2009-12-27 00:00:00",
+    "last day of this week" = "2010-01-02 00:00:00",
+    "last day of last week" = "2009-12-26 00:00:00",
+    "first day of 2 years ago" = "2008-01-01 00:00:00",
+    "1st hour of next month" = "2010-02-01 00:00:00",
+    "25th hour of the week" = "2009-12-28 00:00:00",
+    "end of month" = "2010-01-31 00:00:00",
+    "end of this month" = "2010-01-31 00:00:00",
+    "start of next week" = "2010-01-03 00:00:00",
+    "beginning of the year" = "2010-01-01 00:00:00",
+    "end of next year" = "2011-12-31 00:00:00",
+    "start of last month" = "2009-12-01 00:00:00",
+    "end of february" = "2010-02-28 00:00:00",
+    "end of last week" = "2009-12-26 00:00:00",
+    "beginning of week" = "2009-12-27 00:00:00",
+    "end of tomorrow" = "2010-01-02 00:00:00",
+    "start of 2 months ago" = "2009-11-01 00:00:00",
+    "EOD" = "2010-01-01 00:00:00",
+    "EOW" = "2010-01-02 00:00:00",
+    "by EOW" = "2010-01-02 00:00:00",
+    "EOM" = "2010-01-31 00:00:00",
+    "EOY" = "2010-12-31 00:00:00",
+    "by end of the month" = "2010-01-31 00:00:00",
+    "start of the hour" = "2010-01-01 00:00:00",
+    "start of next hour" = "2010-01-01 01:00:00",
+    "Q3 2024" = "2024-07-01 00:00:00",
+    "Q1" = "2010-01-01 00:00:00",
+    "Q4" = "2010-10-01 00:00:00",
+    "Q2 2024 13:00" = "2024-04-01 13:00:00",
+    "next quarter" = "2010-04-01 00:00:00",
+    "last quarter" = "2009-10-01 00:00:00",
+    "in 2 quarters" = "2010-07-01 00:00:00",
+    "a quarter ago" = "2009-10-01 00:00:00",
+    "first day of the quarter" = "2010-01-01 00:00:00",
+    "last day of the quarter" = "2010-03-31 00:00:00",
+    "last day of next quarter" = "2010-06-30 00:00:00",
+    "last month of the quarter" = "2010-03-01 00:00:00",
+    "second quarter of next year" = "2011-04-01 00:00:00",
+    "last quarter of last year" = "2009-10-01 00:00:00",
+    "end of Q3 2024" = "2024-09-30 00:00:00",
+    "first day of Q2" = "2010-04-01 00:00:00",
+    "EOQ" = "2010-03-31 00:00:00",
+    "end of fiscal year" = "2010-12-31 00:00:00",
+    "FY25 Q2" = "2025-04-01 00:00:00",
+    "Q3 FY2025" = "2025-07-01 00:00:00",
+    "FY 2024" = "2024-01-01 00:00:00",
+    "week 42" = "2010-10-18 00:00:00",
+    "week 42 of 2024" = "2024-10-14 00:00:00",
+    "week 1 of 2010" = "2010-01-04 00:00:00",
+    "week 53 2009" = "2009-12-28 00:00:00",
+    "CW 7 next year" = "2011-02-14 00:00:00",
+    "week 7 of last year" = "2009-02-09 00:00:00",
+    "2024-W42-3" = "2024-10-16 00:00:00",
+    "2024-W42" = "2024-10-14 00:00:00",
+    "2024W42" = "2024-10-14 00:00:00",
+    "2024-W42-3 13:00" = "2024-10-16 13:00:00",
+    "2024-287" = "2024-10-13 00:00:00",
+    "2024-366" = "2024-12-31 00:00:00",
+    "2024-001 08:30" = "2024-01-01 08:30:00",
+    "day 256 of 2025" = "2025-09-13 00:00:00",
+    "day 256" = "2010-09-13 00:00:00",
+    "day 1 of next year" = "2011-01-01 00:00:00",
+    "day 60 2024" = "2024-02-29 00:00:00",
+    "2 days after tomorrow" = "2010-01-04 00:00:00",
+    "a week before 2010-02-14" = "2010-02-07 00:00:00",
+    "the friday before 2010-02-14" = "2010-02-12 00:00:00",
+    "monday after 2010-02-15" = "2010-02-22 00:00:00",
+    "in 3 business days" = "2010-01-06 00:00:00",
+    "2 working days ago" = "2009-12-30 00:00:00",
+    "next business day" = "2010-01-04 00:00:00",
+    "last business day" = "2009-12-31 00:00:00",
+    "last working day of the month" = "2010-01-29 00:00:00",
+    "first business day of next month" = "2010-02-01 00:00:00",
+    "3rd business day of the year" = "2010-01-05 00:00:00",
+    "this weekend" = "2010-01-02 00:00:00",
+    "the weekend" = "2010-01-02 00:00:00",
+    "next weekend" = "2010-01-09 00:00:00",
+    "last weekend" = "2009-12-26 00:00:00",
+    "the weekend after next" = "2010-01-16 00:00:00",
+    "this weekend at 10:00" = "2010-01-02 10:00:00",
+    "every weekday" = "2010-01-01 00:00:00",
+    "in 4 working hours" = "2010-01-01 13:00:00",
+    "in 8 business hours" = "2010-01-01 17:00:00",
+    "in 10 business hours" = "2010-01-04 11:00:00",
+    "3 business hours ago" = "2009-12-31 14:00:00",
+    "close of business" = "2010-01-01 17:00:00",
+    "start of business" = "2010-01-01 09:00:00",
+    "COB" = "2010-01-01 17:00:00",
+    "by end of business tomorrow" = "2010-01-04 17:00:00",
+    "close of business on 2010-02-12" = "2010-02-12 17:00:00",
+    "end of business day" = "2010-01-01 17:00:00",
+    "next summer" = "2010-06-01 00:00:00",
+    "last summer" = "2009-06-01 00:00:00",
+    "this winter" = "2009-12-01 00:00:00",
+    "winter" = "2009-12-01 00:00:00",
+    "next winter" = "2010-12-01 00:00:00",
+    "last winter" = "2008-12-01 00:00:00",
+    "winter 2024" = "2024-12-01 00:00:00",
+    "summer of 2024" = "2024-06-01 00:00:00",
+    "spring next year" = "2011-03-01 00:00:00",
+    "fall" = "2010-09-01 00:00:00",
+    "start of spring" = "2010-03-01 00:00:00",
+    "end of summer" = "2010-08-31 00:00:00",
+    "end of winter" = "2010-02-28 00:00:00",
+    "first day of next autumn" = "2010-09-01 00:00:00",
+    "last day of the season" = "2010-02-28 00:00:00",
+    "next summer at 10:00" = "2010-06-01 10:00:00",
+    "mid-March" = "2010-03-15 00:00:00",
+    "early March" = "2010-03-05 00:00:00",
+    "late March" = "2010-03-26 00:00:00",
+    "middle of next month" = "2010-02-14 00:00:00",
+    "early next week" = "2010-01-03 00:00:00",
+    "late 2024" = "2024-10-31 00:00:00",
+    "mid-Q3" = "2010-08-15 00:00:00",
+    "early in the year" = "2010-03-02 00:00:00",
+    "end of 2024" = "2024-12-31 00:00:00",
+    "first day of march 2024" = "2024-03-01 00:00:00",
+    "last day of year 2012" = "2012-12-31 00:00:00",
+    "2024" = "2024-01-01 00:00:00",
+    "in 2015" = "2015-01-01 00:00:00",
+    "March 2024" = "2024-03-01 00:00:00",
+    "March, 2024" = "2024-03-01 00:00:00",
+    "in March" = "2010-03-01 00:00:00",
+    "next April" = "2010-04-01 00:00:00",
+    "next January" = "2011-01-01 00:00:00",
+    "this January" = "2010-01-01 00:00:00",
+    "last March" = "2009-03-01 00:00:00",
+    "March 2024 at 10:00" = "2024-03-01 10:00:00",
+    "2 days before March 2024" = "2024-02-28 00:00:00",
+    "2024 days ago" = "2004-06-17 00:00:00",
+    "in 2024 days" = "2015-07-18 00:00:00",
+    "7 February next year" = "2011-02-07 00:00:00",
+    "7 February last year" = "2009-02-07 00:00:00",
+    "March 5" = "2010-03-05 00:00:00",
+    "March 5 next year" = "2011-03-05 00:00:00",
+    "March 5, 2024" = "2024-03-05 00:00:00",
+    "March 5 2024 at 10:00" = "2024-03-05 10:00:00",
+    "Friday at 24:00" = "2010-01-09 00:00:00",
+    "2022-11-07 24:00:00" = "2022-11-08 00:00:00",
+    "23:59:60" = "2010-01-01 23:59:60",
+    "2016-12-31 23:59:60" = "2016-12-31 23:59:60",
+    "1530 hours" = "2010-01-01 15:30:00",
+    "0900 hrs" = "2010-01-01 09:00:00",
+    "2145hrs" = "2010-01-01 21:45:00",
+    "15h30" = "2010-01-01 15:30:00",
+    "9h" = "2010-01-01 09:00:00",
+    "Tomorrow 15h30" = "2010-01-02 15:30:00",
+    "15h30 next Tuesday" = "2010-01-05 15:30:00",
+    "Tomorrow at 0600 hours" = "2010-01-02 06:00:00",
+    "1000 hours ago" = "2009-11-20 08:00:00",
+    "1h 30m ago" = "2009-12-31 22:30:00",
+    "in 9h" = "2010-01-01 09:00:00",
+    "1530" = "1530-01-01 00:00:00",
+    "13:25:30.125" = "2010-01-01 13:25:30.125",
+    "in 5 seconds and 500ms" = "2010-01-01 00:00:05.5",
+    "a fortnight ago" = "2009-12-18 00:00:00",
+    "in 2 fortnights" = "2010-01-29 00:00:00",
+    "in two decades" = "2030-01-01 00:00:00",
+    "2 decades ago" = "1990-01-01 00:00:00",
+    "a century ago" = "1910-01-01 00:00:00",
+    "in a millennium" = "3010-01-01 00:00:00",
+    "this decade" = "2010-01-01 00:00:00",
+    "next decade" = "2020-01-01 00:00:00",
+    "the first year of the century" = "2000-01-01 00:00:00",
+    "last year of the decade" = "2019-01-01 00:00:00",
+    "last day of the decade" = "2019-12-31 00:00:00",
+    "first day of last decade" = "2000-01-01 00:00:00",
+    "third decade of the century" = "2020-01-01 00:00:00",
+    "first century of the millennium" = "2000-01-01 00:00:00",
+    "end of the century" = "2099-12-31 00:00:00",
+    "last day of the fortnight" = "2010-01-09 00:00:00",
+    "2 Dec" = "2010-12-02 00:00:00",
+    "the 15th of next month" = "2010-02-15 00:00:00",
+    "the 1st of next month" = "2010-02-01 00:00:00",
+    "the first of March" = "2010-03-01 00:00:00",
+    "the last of next month" = "2010-02-28 00:00:00",
+    "15th of March 2024" = "2024-03-15 00:00:00",
+    "the 5th of March next year" = "2011-03-05 00:00:00",
+    "the 5th of this month at 10:00" = "2010-01-05 10:00:00",
+    "the 5th" = "2010-01-05 00:00:00",
+    "on the 5th" = "2010-01-05 00:00:00",
+    "the 1st" = "2010-01-01 00:00:00",
+    "the fifth" = "2010-01-05 00:00:00",
+    "the 31st" = "2010-01-31 00:00:00",
+    "the 21st at 10:00" = "2010-01-21 10:00:00",
+    "next month" = "2010-02-01 00:00:00",
+    "last month" = "2009-12-01 00:00:00",
+    "7 feb 15" = "2015-02-07 00:00:00",
+    "07 February 99" = "1999-02-07 00:00:00",
+    "Q3 24" = "2024-07-01 00:00:00",
+    "week 10 of 15" = "2015-03-02 00:00:00"
 );
 
 #[test]
@@ -182,6 +381,7 @@ fn test_week_start_monday_config() {
 
     let config = crate::ParseConfig {
         week_start_day: crate::WeekStartDay::Monday,
+        ..Default::default()
     };
 
     // With Monday as first day, "1st day of last week" should be Monday
@@ -205,6 +405,7 @@ fn test_week_start_sunday_config() {
 
     let config = crate::ParseConfig {
         week_start_day: crate::WeekStartDay::Sunday,
+        ..Default::default()
     };
 
     // With Sunday as first day, "1st day of last week" should be Sunday
@@ -219,9 +420,1297 @@ fn test_week_start_sunday_config() {
     }
 }
 
+#[test]
+fn test_period_end_last_instant_config() {
+    let now = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap()
+    );
+
+    let config = crate::ParseConfig {
+        period_end: crate::PeriodEnd::LastInstant,
+        ..Default::default()
+    };
+
+    let result = crate::from_human_time_with_config("end of this month", now, config).unwrap();
+    let expected = NaiveDate::from_ymd_opt(2010, 1, 31).unwrap()
+        .and_hms_nano_opt(23, 59, 59, 999_999_999).unwrap();
+
+    match result {
+        crate::ParseResult::DateTime(datetime) => assert_eq!(datetime, expected),
+        _ => panic!("Expected DateTime result"),
+    }
+
+    // Start of a period is unaffected by the setting
+    let result = crate::from_human_time_with_config("start of next week", now, config).unwrap();
+    match result {
+        crate::ParseResult::Date(date) => assert_eq!(date, NaiveDate::from_ymd_opt(2010, 1, 3).unwrap()),
+        _ => panic!("Expected Date result"),
+    }
+}
+
+#[test]
+fn test_end_of_subday_period() {
+    let now = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+        NaiveTime::from_hms_opt(13, 25, 30).unwrap()
+    );
+
+    let result = from_human_time("end of the hour", now).unwrap();
+    let expected = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap()
+        .and_hms_nano_opt(13, 59, 59, 999_999_999).unwrap();
+
+    match result {
+        ParseResult::DateTime(datetime) => assert_eq!(datetime, expected),
+        _ => panic!("Expected DateTime result"),
+    }
+}
+
+#[test]
+fn test_fiscal_year_start_config() {
+    let now = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap()
+    );
+
+    let config = crate::ParseConfig {
+        fiscal_year_start: chrono::Month::October,
+        ..Default::default()
+    };
+
+    let cases = [
+        ("FY25", "2024-10-01"),
+        ("FY25 Q2", "2025-01-01"),
+        ("end of fiscal year", "2010-09-30"),
+        ("start of next fiscal year", "2010-10-01"),
+        ("first day of the fiscal quarter", "2010-01-01"),
+        ("third fiscal quarter of the fiscal year", "2010-04-01"),
+        ("last day of FY2010", "2010-09-30"),
+        // Calendar quarters ignore the fiscal year
+        ("Q1", "2010-01-01"),
+        ("end of the quarter", "2010-03-31"),
+    ];
+
+    for (input, expected) in cases {
+        let result = crate::from_human_time_with_config(input, now, config).unwrap();
+        let expected = NaiveDate::parse_from_str(expected, "%Y-%m-%d").unwrap();
+
+        match result {
+            crate::ParseResult::Date(date) => assert_eq!(date, expected, "{input}"),
+            _ => panic!("Expected Date result for {input}"),
+        }
+    }
+}
+
+#[test]
+fn test_us_week_numbering_config() {
+    let now = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap()
+    );
+
+    let sunday = crate::ParseConfig {
+        week_numbering: crate::WeekNumbering::Us,
+        ..Default::default()
+    };
+    let monday = crate::ParseConfig {
+        week_start_day: crate::WeekStartDay::Monday,
+        ..sunday
+    };
+
+    let cases = [
+        ("week 1", sunday, "2009-12-27"),
+        ("week 42", sunday, "2010-10-10"),
+        ("week 53", sunday, "2010-12-26"),
+        ("week 42", monday, "2010-10-11"),
+        // ISO week dates are not affected by the setting
+        ("2024-W42-3", sunday, "2024-10-16"),
+    ];
+
+    for (input, config, expected) in cases {
+        let result = crate::from_human_time_with_config(input, now, config).unwrap();
+        let expected = NaiveDate::parse_from_str(expected, "%Y-%m-%d").unwrap();
+
+        match result {
+            crate::ParseResult::Date(date) => assert_eq!(date, expected, "{input}"),
+            _ => panic!("Expected Date result for {input}"),
+        }
+    }
+
+    assert!(crate::from_human_time_with_config("week 54", now, sunday).is_err());
+}
+
+#[test]
+fn test_holiday_calendar_config() {
+    let now = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap()
+    );
+
+    let cases = [
+        (&crate::holidays::US, "Christmas", "2010-12-25"),
+        (&crate::holidays::US, "next Easter", "2010-04-04"),
+        (&crate::holidays::US, "last Easter", "2009-04-12"),
+        (&crate::holidays::US, "last new year's day", "2009-01-01"),
+        (&crate::holidays::US, "next new year's day", "2011-01-01"),
+        (&crate::holidays::US, "2 days before Thanksgiving", "2010-11-23"),
+        (&crate::holidays::US, "the Monday after Labor Day", "2010-09-13"),
+        (&crate::holidays::US, "a week after new year's day", "2010-01-08"),
+        (&crate::holidays::US, "Memorial Day", "2010-05-31"),
+        (&crate::holidays::US, "MLK Day", "2010-01-18"),
+        (&crate::holidays::UK, "Boxing Day", "2010-12-26"),
+        (&crate::holidays::UK, "spring bank holiday", "2010-05-31"),
+        (&crate::holidays::UK, "Good Friday", "2010-04-02"),
+        (&crate::holidays::GERMANY, "Pfingstmontag", "2010-05-24"),
+        (&crate::holidays::GERMANY, "Tag der Deutschen Einheit", "2010-10-03"),
+        (&crate::holidays::GERMANY, "Christi Himmelfahrt", "2010-05-13"),
+        (&crate::holidays::US, "Christmas last year", "2009-12-25"),
+        (&crate::holidays::US, "Easter next year", "2011-04-24"),
+        (&crate::holidays::UK, "Boxing Day 2024", "2024-12-26"),
+        (&crate::holidays::US, "Thanksgiving of 2024", "2024-11-28"),
+    ];
+
+    for (calendar, input, expected) in cases {
+        let config = crate::ParseConfig {
+            holiday_calendar: Some(calendar),
+            ..Default::default()
+        };
+        let result = crate::from_human_time_with_config(input, now, config).unwrap();
+        let expected = NaiveDate::parse_from_str(expected, "%Y-%m-%d").unwrap();
+
+        match result {
+            crate::ParseResult::Date(date) => assert_eq!(date, expected, "{input}"),
+            _ => panic!("Expected Date result for {input}"),
+        }
+    }
+
+    let config = crate::ParseConfig {
+        holiday_calendar: Some(&crate::holidays::US),
+        ..Default::default()
+    };
+    let result = crate::from_human_time_with_config("Christmas at 18:00", now, config).unwrap();
+    let expected = NaiveDate::from_ymd_opt(2010, 12, 25).unwrap().and_hms_opt(18, 0, 0).unwrap();
+    assert!(matches!(result, crate::ParseResult::DateTime(datetime) if datetime == expected));
+
+    assert!(crate::from_human_time_with_config("Festivus", now, config).is_err());
+    assert!(crate::from_human_time_with_config("Boxing Day", now, config).is_err());
+}
+
+#[test]
+fn test_holiday_missing_in_some_years() {
+    /// Only has an offsite in 2024.
+    #[derive(Debug)]
+    struct Offsite;
+
+    impl crate::HolidayCalendar for Offsite {
+        fn holiday(&self, name: &str, year: i32) -> Option<NaiveDate> {
+            (name == "offsite" && year == 2024).then(|| NaiveDate::from_ymd_opt(2024, 6, 10).unwrap())
+        }
+
+        fn is_holiday(&self, date: NaiveDate) -> bool {
+            self.holiday("offsite", date.year()) == Some(date)
+        }
+    }
+
+    static OFFSITE: Offsite = Offsite;
+    let config = crate::ParseConfig {
+        holiday_calendar: Some(&OFFSITE),
+        ..Default::default()
+    };
+    let now = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    let expected = ParseResult::Date(NaiveDate::from_ymd_opt(2024, 6, 10).unwrap());
+
+    for input in ["offsite", "offsite 2024", "next offsite"] {
+        assert_eq!(crate::from_human_time_with_config(input, now, config).unwrap(), expected, "{input}");
+    }
+
+    let now = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    let result = crate::from_human_time_with_config("offsite", now, config);
+    assert!(
+        matches!(&result, Err(ParseError::ProccessingErrors(errors)) if matches!(errors[..], [ProcessingError::UnknownHoliday { .. }])),
+        "{result:?}"
+    );
+
+    // An explicit year is looked up even far from the current one.
+    let now = NaiveDate::from_ymd_opt(2027, 3, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    assert_eq!(crate::from_human_time_with_config("offsite 2024", now, config).unwrap(), expected);
+    assert!(matches!(
+        crate::from_human_time_with_config("offsite", now, config),
+        Err(ParseError::InvalidFormat)
+    ));
+}
+
+#[test]
+fn test_config_is_unwind_safe() {
+    let now = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    let config = crate::ParseConfig {
+        holiday_calendar: Some(&crate::holidays::US),
+        ..Default::default()
+    };
+
+    let result = std::panic::catch_unwind(|| crate::from_human_time_with_config("Christmas", now, config));
+    assert!(matches!(result, Ok(Ok(ParseResult::Date(_)))));
+}
+
+#[test]
+fn test_unknown_words_are_invalid_format() {
+    let now = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap()
+    );
+    let with_calendar = crate::ParseConfig {
+        holiday_calendar: Some(&crate::holidays::US),
+        ..Default::default()
+    };
+
+    for config in [crate::ParseConfig::default(), with_calendar] {
+        for input in ["garbage", "hello world", "2 days before garbage", "next festivus"] {
+            let result = crate::from_human_time_with_config(input, now, config);
+            assert!(matches!(result, Err(ParseError::InvalidFormat)), "{input}: {result:?}");
+        }
+    }
+
+    // Without a calendar there are no holidays at all.
+    let result = crate::from_human_time_with_config("Christmas", now, crate::ParseConfig::default());
+    assert!(matches!(result, Err(ParseError::InvalidFormat)), "{result:?}");
+}
+
+#[test]
+fn test_business_days_skip_holidays() {
+    let config = crate::ParseConfig {
+        holiday_calendar: Some(&crate::holidays::US),
+        ..Default::default()
+    };
+
+    let cases = [
+        ("2009-12-24", "next business day", "2009-12-28"),
+        ("2009-12-15", "first business day of next month", "2010-01-04"),
+        ("2010-05-27", "in 5 business days", "2010-06-04"),
+        ("2010-06-01", "2 working days ago", "2010-05-27"),
+        ("2010-01-01", "first working day of the year", "2010-01-04"),
+    ];
+
+    for (now, input, expected) in cases {
+        let now = NaiveDate::parse_from_str(now, "%Y-%m-%d").unwrap().and_hms_opt(9, 0, 0).unwrap();
+        let expected = NaiveDate::parse_from_str(expected, "%Y-%m-%d").unwrap();
+        let result = crate::from_human_time_with_config(input, now, config).unwrap();
+
+        match result {
+            crate::ParseResult::Date(date) => assert_eq!(date, expected, "{input}"),
+            crate::ParseResult::DateTime(datetime) => {
+                assert_eq!(datetime, expected.and_hms_opt(9, 0, 0).unwrap(), "{input}")
+            }
+            _ => panic!("Expected Date or DateTime result for {input}"),
+        }
+    }
+}
+
+#[test]
+fn test_business_days_match_day_by_day_count() {
+    let configs = [
+        crate::ParseConfig {
+            holiday_calendar: Some(&crate::holidays::US),
+            ..Default::default()
+        },
+        crate::ParseConfig {
+            holiday_calendar: Some(&crate::holidays::GERMANY),
+            weekend: chrono::WeekdaySet::from_array([chrono::Weekday::Fri, chrono::Weekday::Sat]),
+            ..Default::default()
+        },
+    ];
+    let start = NaiveDate::from_ymd_opt(2009, 12, 20).unwrap();
+
+    for config in configs {
+        for direction in [Direction::Forwards, Direction::Backwards] {
+            let mut expected = start;
+            for count in 0..400 {
+                let result = add_business_days(start, count, &direction, config).unwrap();
+                assert_eq!(result, expected, "{count} business days {}", direction == Direction::Forwards);
+
+                expected = match direction {
+                    Direction::Forwards => expected.iter_days().skip(1).find(|date| is_business_day(*date, config)),
+                    Direction::Backwards => expected.iter_days().rev().skip(1).find(|date| is_business_day(*date, config)),
+                }
+                .unwrap();
+            }
+        }
+    }
+}
+
+#[test]
+fn test_business_hours_match_hour_by_hour_count() {
+    let config = crate::ParseConfig {
+        business_hours: crate::BusinessHours {
+            open: NaiveTime::from_hms_opt(8, 30, 0).unwrap(),
+            close: NaiveTime::from_hms_opt(16, 45, 0).unwrap(),
+        },
+        holiday_calendar: Some(&crate::holidays::US),
+        ..Default::default()
+    };
+    let start = NaiveDate::from_ymd_opt(2009, 12, 20).unwrap().and_hms_opt(12, 0, 0).unwrap();
+
+    for direction in [Direction::Forwards, Direction::Backwards] {
+        let mut expected = add_business_hours(start, 0, &direction, config).unwrap();
+        for count in 0..300 {
+            let result = add_business_hours(start, count, &direction, config).unwrap();
+            assert_eq!(result, expected, "{count} business hours {}", direction == Direction::Forwards);
+            expected = add_business_hours(expected, 1, &direction, config).unwrap();
+        }
+    }
+
+    let now = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    let errors = match crate::from_human_time_with_config("in 4294967295 working hours", now, config) {
+        Err(ParseError::ProccessingErrors(errors)) => errors,
+        result => panic!("{result:?}"),
+    };
+    assert!(
+        matches!(&errors[..], [ProcessingError::AddToDate { unit, count: 4294967295, date }] if unit == "business hours" && *date == now),
+        "{errors:?}"
+    );
+}
+
+#[test]
+fn test_business_days_without_business_days() {
+    /// Every day is a holiday.
+    #[derive(Debug)]
+    struct AlwaysOff;
+
+    impl crate::HolidayCalendar for AlwaysOff {
+        fn holiday(&self, _name: &str, _year: i32) -> Option<NaiveDate> {
+            None
+        }
+
+        fn is_holiday(&self, _date: NaiveDate) -> bool {
+            true
+        }
+    }
+
+    static ALWAYS_OFF: AlwaysOff = AlwaysOff;
+    let now = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(9, 0, 0).unwrap();
+    let every_day = crate::ParseConfig {
+        weekend: chrono::WeekdaySet::ALL,
+        ..Default::default()
+    };
+    let no_days_off = crate::ParseConfig {
+        holiday_calendar: Some(&ALWAYS_OFF),
+        ..Default::default()
+    };
+
+    for input in ["in 3 business days", "in 1000 business days", "2 working days ago", "in 20 business hours"] {
+        let errors = match crate::from_human_time_with_config(input, now, every_day) {
+            Err(ParseError::ProccessingErrors(errors)) => errors,
+            result => panic!("{input}: {result:?}"),
+        };
+        assert!(matches!(errors[..], [ProcessingError::InvalidWeekend]), "{input}: {errors:?}");
+
+        let errors = match crate::from_human_time_with_config(input, now, no_days_off) {
+            Err(ParseError::ProccessingErrors(errors)) => errors,
+            result => panic!("{input}: {result:?}"),
+        };
+        assert!(matches!(errors[..], [ProcessingError::NoBusinessDays { .. }]), "{input}: {errors:?}");
+    }
+
+    let config = crate::ParseConfig {
+        holiday_calendar: Some(&crate::holidays::US),
+        ..Default::default()
+    };
+    assert!(crate::from_human_time_with_config("in 5000000 business days", now, config).is_ok());
+}
+
+#[test]
+fn test_weekend_config() {
+    let friday = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap()
+    );
+    let sunday = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 1, 3).unwrap(),
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap()
+    );
+
+    let default = crate::ParseConfig::default();
+    let middle_east = crate::ParseConfig {
+        weekend: chrono::WeekdaySet::from_array([chrono::Weekday::Fri, chrono::Weekday::Sat]),
+        ..Default::default()
+    };
+
+    let cases = [
+        (default, sunday, "this weekend", "2010-01-02"),
+        (default, sunday, "last weekend", "2009-12-26"),
+        (default, sunday, "every weekday", "2010-01-04"),
+        (middle_east, friday, "this weekend", "2010-01-01"),
+        (middle_east, friday, "next weekend", "2010-01-08"),
+        (middle_east, friday, "every weekday", "2010-01-03"),
+        (middle_east, friday, "in 2 business days", "2010-01-04"),
+        (middle_east, sunday, "last working day of the month", "2010-01-31"),
+    ];
+
+    for (config, now, input, expected) in cases {
+        let result = crate::from_human_time_with_config(input, now, config).unwrap();
+        let expected = NaiveDate::parse_from_str(expected, "%Y-%m-%d").unwrap();
+
+        match result {
+            crate::ParseResult::Date(date) => assert_eq!(date, expected, "{input}"),
+            crate::ParseResult::DateTime(datetime) => assert_eq!(datetime.date(), expected, "{input}"),
+            _ => panic!("Expected Date result for {input}"),
+        }
+    }
+
+    let no_weekend = crate::ParseConfig {
+        weekend: chrono::WeekdaySet::EMPTY,
+        ..Default::default()
+    };
+    assert!(crate::from_human_time_with_config("next weekend", friday, no_weekend).is_err());
+
+    // Recurrences aren't modelled. "Every weekday" is only its next occurrence, even as a range.
+    let range = crate::ParseConfig {
+        evaluation_mode: crate::EvaluationMode::Range,
+        ..default
+    };
+    for input in ["every weekday", "weekdays"] {
+        let expected = crate::ParseResult::Date(NaiveDate::from_ymd_opt(2010, 1, 4).unwrap());
+        assert_eq!(crate::from_human_time_with_config(input, sunday, range).unwrap(), expected, "{input}");
+    }
+}
+
+#[test]
+fn test_day_of_month_config() {
+    let at = |date: &str| NaiveDateTime::new(
+        NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+        NaiveTime::from_hms_opt(12, 0, 0).unwrap()
+    );
+    let future_clamp = crate::ParseConfig {
+        missing_day: crate::MissingDay::Clamp,
+        ..Default::default()
+    };
+    let past = crate::ParseConfig {
+        preference: crate::Preference::Past,
+        ..Default::default()
+    };
+    let past_clamp = crate::ParseConfig {
+        missing_day: crate::MissingDay::Clamp,
+        ..past
+    };
+
+    let cases = [
+        (crate::ParseConfig::default(), "2010-01-31", "the 30th", "2010-03-30"),
+        (future_clamp, "2010-01-31", "the 30th", "2010-02-28"),
+        (crate::ParseConfig::default(), "2010-04-05", "the 31st", "2010-05-31"),
+        (future_clamp, "2010-04-05", "the 31st", "2010-04-30"),
+        (past, "2010-03-15", "the 20th", "2010-02-20"),
+        (past, "2010-03-15", "the 15th", "2010-03-15"),
+        (past, "2010-03-15", "the 30th", "2010-01-30"),
+        (past_clamp, "2010-03-15", "the 30th", "2010-02-28"),
+    ];
+
+    for (config, now, input, expected) in cases {
+        let result = crate::from_human_time_with_config(input, at(now), config).unwrap();
+        let expected = NaiveDate::parse_from_str(expected, "%Y-%m-%d").unwrap();
+        assert_eq!(result, ParseResult::Date(expected), "{input} at {now}");
+    }
+}
+
+#[test]
+fn test_preference_config() {
+    let at = |datetime: &str| NaiveDateTime::parse_from_str(datetime, "%Y-%m-%d %H:%M").unwrap();
+    let date = |date: &str| ParseResult::Date(NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap());
+    let datetime = |datetime: &str| ParseResult::DateTime(at(datetime));
+    let time = |time: &str| ParseResult::Time(NaiveTime::parse_from_str(time, "%H:%M").unwrap());
+    let config = |preference| crate::ParseConfig {
+        preference,
+        holiday_calendar: Some(&crate::holidays::US),
+        ..Default::default()
+    };
+
+    let future = config(crate::Preference::Future);
+    let past = config(crate::Preference::Past);
+    let nearest = config(crate::Preference::Nearest);
+
+    // 2010-03-01 is a Monday.
+    let cases = [
+        (future, "2010-03-01 12:00", "07 February", date("2011-02-07")),
+        (future, "2010-03-01 12:00", "March 1", date("2010-03-01")),
+        (future, "2010-03-01 12:00", "Friday", date("2010-03-05")),
+        (future, "2010-03-01 12:00", "Monday", date("2010-03-08")),
+        (future, "2010-03-01 12:00", "Christmas", date("2010-12-25")),
+        (future, "2010-03-01 12:00", "10:00", datetime("2010-03-02 10:00")),
+        (future, "2010-03-01 12:00", "15:00", time("15:00")),
+        (past, "2010-03-01 12:00", "07 February", date("2010-02-07")),
+        (past, "2010-03-01 12:00", "Friday", date("2010-02-26")),
+        (past, "2010-03-01 12:00", "Christmas", date("2009-12-25")),
+        (past, "2010-03-01 12:00", "15:00", datetime("2010-02-28 15:00")),
+        (past, "2010-03-01 12:00", "10:00", time("10:00")),
+        (past, "2010-03-01 12:00", "Friday at 10:00", datetime("2010-02-26 10:00")),
+        (nearest, "2010-03-01 12:00", "07 February", date("2010-02-07")),
+        (nearest, "2010-03-01 12:00", "25 December", date("2009-12-25")),
+        (nearest, "2010-03-01 12:00", "Saturday", date("2010-02-27")),
+        (nearest, "2010-03-01 12:00", "Thursday", date("2010-03-04")),
+        (nearest, "2010-03-01 12:00", "the 30th", date("2010-03-30")),
+        (nearest, "2010-03-01 12:00", "23:00", time("23:00")),
+        (nearest, "2010-03-01 01:00", "23:00", datetime("2010-02-28 23:00")),
+        (future, "2010-03-01 12:00", "29 February", date("2012-02-29")),
+        (past, "2010-03-01 12:00", "29 February", date("2008-02-29")),
+        (nearest, "2010-03-01 12:00", "29 February", date("2012-02-29")),
+        (future, "2097-03-01 12:00", "29 February", date("2104-02-29")),
+        (future, "2010-03-01 12:00", "7 February this year", date("2010-02-07")),
+        (future, "2010-03-01 12:00", "today at 10:00", datetime("2010-03-01 10:00")),
+        (future, "2010-03-01 12:00", "2 weeks ago at 10:00", datetime("2010-02-15 10:00")),
+        (past, "2010-03-01 12:00", "2 weeks ago at 15:00", datetime("2010-02-15 15:00")),
+        (future, "2010-03-01 12:00", "2 days ago at 7 February", datetime("2010-02-05 12:00")),
+    ];
+
+    for (config, now, input, expected) in cases {
+        let result = crate::from_human_time_with_config(input, at(now), config).unwrap();
+        assert_eq!(result, expected, "{input} at {now} with {:?}", config.preference);
+    }
+}
+
+#[test]
+fn test_plural_units_before_weekdays() {
+    let now = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 1, 6).unwrap(),
+        NaiveTime::from_hms_opt(12, 0, 0).unwrap()
+    );
+
+    // The "s" of Saturday and Sunday is not a plural "weeks".
+    for (input, weekday) in [("next week Saturday", Weekday::Sat), ("next week Sunday", Weekday::Sun)] {
+        match from_human_time(input, now).unwrap() {
+            ParseResult::Date(date) => assert_eq!(date.weekday(), weekday, "{input}"),
+            result => panic!("{input} gave {result:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_relative_weekdays_config() {
+    // 2010-01-06 is a Wednesday.
+    let now = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 1, 6).unwrap(),
+        NaiveTime::from_hms_opt(12, 0, 0).unwrap()
+    );
+    let calendar_week = crate::ParseConfig {
+        relative_weekdays: crate::RelativeWeekdays::CalendarWeek,
+        ..Default::default()
+    };
+    let calendar_week_monday = crate::ParseConfig {
+        week_start_day: crate::WeekStartDay::Monday,
+        ..calendar_week
+    };
+    let monday = crate::ParseConfig {
+        week_start_day: crate::WeekStartDay::Monday,
+        ..Default::default()
+    };
+
+    let cases = [
+        (crate::ParseConfig::default(), "next Friday", "2010-01-08"),
+        (crate::ParseConfig::default(), "next Sunday", "2010-01-10"),
+        (calendar_week, "next Friday", "2010-01-15"),
+        (calendar_week, "this Friday", "2010-01-08"),
+        (calendar_week, "last Friday", "2010-01-01"),
+        (calendar_week, "this Monday", "2010-01-04"),
+        (calendar_week, "next Sunday", "2010-01-10"),
+        (calendar_week_monday, "next Sunday", "2010-01-17"),
+        (calendar_week_monday, "this Sunday", "2010-01-10"),
+        (calendar_week_monday, "last Sunday", "2010-01-03"),
+        (crate::ParseConfig::default(), "next week Sunday", "2010-01-10"),
+        (monday, "next week Sunday", "2010-01-17"),
+        (monday, "this week Sunday", "2010-01-10"),
+    ];
+
+    for (config, input, expected) in cases {
+        let result = crate::from_human_time_with_config(input, now, config).unwrap();
+        let expected = NaiveDate::parse_from_str(expected, "%Y-%m-%d").unwrap();
+        assert_eq!(result, ParseResult::Date(expected), "{input} with {config:?}");
+    }
+}
+
+#[test]
+fn test_next_month_name() {
+    let at = |now: &str| NaiveDate::parse_from_str(now, "%Y-%m-%d").unwrap().and_hms_opt(0, 0, 0).unwrap();
+
+    // "Next April" is the first April after the current month, whichever way it is written.
+    let cases = [
+        ("2024-01-15", "2024-04-01"),
+        ("2024-04-15", "2025-04-01"),
+        ("2024-06-15", "2025-04-01"),
+    ];
+
+    for (now, expected) in cases {
+        let expected = NaiveDate::parse_from_str(expected, "%Y-%m-%d").unwrap();
+        assert_eq!(
+            from_human_time("next April", at(now)).unwrap(),
+            ParseResult::YearMonth(expected.year(), chrono::Month::April),
+            "next April at {now}"
+        );
+        for input in ["the 1st of next April", "first day of next April"] {
+            assert_eq!(from_human_time(input, at(now)).unwrap(), ParseResult::Date(expected), "{input} at {now}");
+        }
+    }
+}
+
+#[test]
+fn test_weeks_of_a_period_ignore_week_start() {
+    let now = NaiveDate::from_ymd_opt(2010, 6, 15).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    let config = |week_start_day| crate::ParseConfig {
+        week_start_day,
+        ..Default::default()
+    };
+
+    // 2010 starts on a Friday. Its weeks are counted from January 1st, so the 53rd and last one
+    // is only December 31st.
+    let cases = [
+        ("first week of the year", "2010-01-01"),
+        ("2nd week of the year", "2010-01-08"),
+        ("52nd week of the year", "2010-12-24"),
+        ("last week of the year", "2010-12-31"),
+        ("53rd week of the year", "2010-12-31"),
+    ];
+
+    for week_start_day in [crate::WeekStartDay::Sunday, crate::WeekStartDay::Monday] {
+        for (input, expected) in cases {
+            let expected = NaiveDate::parse_from_str(expected, "%Y-%m-%d").unwrap();
+            let result = crate::from_human_time_with_config(input, now, config(week_start_day)).unwrap();
+            assert_eq!(result, ParseResult::Date(expected), "{input} with {week_start_day:?}");
+        }
+    }
+}
+
+#[test]
+fn test_month_is_not_monday() {
+    let now = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    let cases = [
+        ("next month", "2010-02-01"),
+        ("last month", "2009-12-01"),
+        ("next mon", "2010-01-04"),
+        ("last mon", "2009-12-28"),
+    ];
+
+    for (input, expected) in cases {
+        let expected = NaiveDate::parse_from_str(expected, "%Y-%m-%d").unwrap();
+        assert_eq!(from_human_time(input, now).unwrap(), ParseResult::Date(expected), "{input}");
+    }
+}
+
+#[test]
+fn test_day_overflow_config() {
+    let at = |date: &str| NaiveDateTime::new(
+        NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap()
+    );
+    let config = |day_overflow| crate::ParseConfig {
+        day_overflow,
+        ..Default::default()
+    };
+    let clamp = config(crate::DayOverflow::Clamp);
+    let next_month = config(crate::DayOverflow::NextMonth);
+    let error = config(crate::DayOverflow::Error);
+
+    let cases = [
+        (clamp, "2024-02-29", "a year ago", Some("2023-02-28")),
+        (clamp, "2024-02-29", "in 1 year", Some("2025-02-28")),
+        (clamp, "2024-02-29", "next year", Some("2025-02-28")),
+        (clamp, "2024-02-29", "december 1 year ago", Some("2023-12-01")),
+        (next_month, "2024-02-29", "a year ago", Some("2023-03-01")),
+        (error, "2024-02-29", "a year ago", None),
+        (error, "2024-02-29", "next year", None),
+        (error, "2024-02-29", "december 1 year ago", None),
+        (error, "2024-02-29", "april 1 year from now", None),
+        (error, "2024-02-29", "in 4 years", Some("2028-02-29")),
+        (clamp, "2010-01-31", "in 1 month", Some("2010-02-28")),
+        (next_month, "2010-01-31", "in 1 month", Some("2010-03-03")),
+        (error, "2010-01-31", "in 1 month", None),
+        (error, "2010-01-30", "in 2 months", Some("2010-03-30")),
+        (clamp, "2010-01-31", "in 1 quarter", Some("2010-04-30")),
+        (next_month, "2010-01-31", "in 1 quarter", Some("2010-05-01")),
+        (clamp, "2010-03-31", "last month", Some("2010-02-28")),
+        (next_month, "2010-03-31", "last month", Some("2010-03-03")),
+    ];
+
+    for (config, now, input, expected) in cases {
+        let result = crate::from_human_time_with_config(input, at(now), config);
+        match expected {
+            Some(expected) => {
+                let result = match result.unwrap() {
+                    ParseResult::DateTime(datetime) => datetime.date(),
+                    ParseResult::Date(date) => date,
+                    result => panic!("Unexpected result {result:?} for {input}"),
+                };
+                let expected = NaiveDate::parse_from_str(expected, "%Y-%m-%d").unwrap();
+                assert_eq!(result, expected, "{input} at {now} with {:?}", config.day_overflow);
+            }
+            None => assert!(result.is_err(), "{input} at {now} with {:?}", config.day_overflow),
+        }
+    }
+}
+
+#[test]
+fn test_two_digit_years_config() {
+    let now = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap()
+    );
+    let config = |two_digit_years| crate::ParseConfig {
+        two_digit_years,
+        ..Default::default()
+    };
+    let fixed = config(crate::TwoDigitYears::Fixed { pivot: 70 });
+    let literal = config(crate::TwoDigitYears::Literal);
+    let sliding = config(crate::TwoDigitYears::Sliding { years_ahead: 10 });
+
+    let cases = [
+        (fixed, "7 feb 69", "2069-02-07"),
+        (fixed, "7 feb 70", "1970-02-07"),
+        (fixed, "FY69", "2069-01-01"),
+        (literal, "7 feb 15", "0015-02-07"),
+        (literal, "7 feb 2015", "2015-02-07"),
+        (sliding, "7 feb 20", "2020-02-07"),
+        (sliding, "7 feb 21", "1921-02-07"),
+        (sliding, "feb 7 20", "2020-02-07"),
+        (sliding, "feb 7, 21", "1921-02-07"),
+        (fixed, "feb 7 69", "2069-02-07"),
+        (literal, "feb 7 15", "0015-02-07"),
+        (sliding, "day 60 of year 12", "2012-02-29"),
+        (config(crate::TwoDigitYears::Sliding { years_ahead: 1000 }), "7 feb 15", "2915-02-07"),
+    ];
+
+    for (config, input, expected) in cases {
+        let result = crate::from_human_time_with_config(input, now, config).unwrap();
+        let expected = NaiveDate::parse_from_str(expected, "%Y-%m-%d").unwrap();
+        assert_eq!(result, ParseResult::Date(expected), "{input} with {:?}", config.two_digit_years);
+    }
+
+    // Windows past the last representable year don't overflow, they just give invalid dates.
+    for years_ahead in [i32::MAX as u32, u32::MAX] {
+        let config = config(crate::TwoDigitYears::Sliding { years_ahead });
+        assert!(crate::from_human_time_with_config("7 feb 15", now, config).is_err());
+    }
+}
+
+#[test]
+fn test_hour_24_config() {
+    let at = |datetime: &str| NaiveDateTime::parse_from_str(datetime, "%Y-%m-%d %H:%M:%S%.f").unwrap();
+    let now = at("2010-01-01 12:00:00");
+    let config = |hour_24| crate::ParseConfig {
+        hour_24,
+        ..Default::default()
+    };
+    let next_day = config(crate::Hour24::NextDay);
+    let end_of_day = config(crate::Hour24::EndOfDay);
+
+    let cases = [
+        (next_day, "24:00", "2010-01-02 00:00:00"),
+        (next_day, "today at 24:00", "2010-01-02 00:00:00"),
+        (end_of_day, "24:00", "2010-01-01 23:59:59.999999999"),
+        (end_of_day, "today at 24:00", "2010-01-01 23:59:59.999999999"),
+        (end_of_day, "tomorrow 24:00:00", "2010-01-02 23:59:59.999999999"),
+    ];
+
+    for (config, input, expected) in cases {
+        let result = crate::from_human_time_with_config(input, now, config).unwrap();
+        assert_eq!(result.to_datetime(now, TimeFill::Now), Some(at(expected)), "{input} with {:?}", config.hour_24);
+    }
+}
+
+#[test]
+fn test_four_digit_numbers_config() {
+    let at = |datetime: &str| NaiveDateTime::parse_from_str(datetime, "%Y-%m-%d %H:%M").unwrap();
+    let now = at("2010-01-01 12:00");
+    let config = crate::ParseConfig {
+        four_digit_numbers: crate::FourDigitNumbers::Times,
+        ..Default::default()
+    };
+
+    let cases = [
+        ("1530", ParseResult::Time(NaiveTime::from_hms_opt(15, 30, 0).unwrap())),
+        ("at 1530", ParseResult::Time(NaiveTime::from_hms_opt(15, 30, 0).unwrap())),
+        ("0600", ParseResult::DateTime(at("2010-01-02 06:00"))),
+        ("tomorrow 0600", ParseResult::DateTime(at("2010-01-02 06:00"))),
+        ("friday at 2230", ParseResult::DateTime(at("2010-01-08 22:30"))),
+        ("March 5 1530", ParseResult::Date(NaiveDate::from_ymd_opt(1530, 3, 5).unwrap())),
+        ("in 2024", ParseResult::Year(2024)),
+        ("2024-01-01", ParseResult::Date(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())),
+        ("1000 hours ago", ParseResult::DateTime(at("2009-11-20 20:00"))),
+        ("2400", ParseResult::DateTime(at("2010-01-02 00:00"))),
+        ("1999", ParseResult::Year(1999)),
+        ("2460", ParseResult::Year(2460)),
+    ];
+
+    for (input, expected) in cases {
+        let result = crate::from_human_time_with_config(input, now, config).unwrap();
+        assert_eq!(result, expected, "{input}");
+    }
+}
+
+#[test]
+fn test_sub_second_precision() {
+    let at = |datetime: &str| NaiveDateTime::parse_from_str(datetime, "%Y-%m-%d %H:%M:%S%.f").unwrap();
+    let now = at("2010-01-01 12:00:00");
+    let time = |time: &str| NaiveTime::parse_from_str(time, "%H:%M:%S%.f").unwrap();
+
+    let cases = [
+        ("13:25:30.125", ParseResult::Time(time("13:25:30.125"))),
+        ("13:25:30,5", ParseResult::Time(time("13:25:30.5"))),
+        ("13:25:30.000000001", ParseResult::Time(time("13:25:30.000000001"))),
+        ("13:25:30.1234567891", ParseResult::Time(time("13:25:30.123456789"))),
+        ("23:59:60.5", ParseResult::Time(time("23:59:60.5"))),
+        ("2022-11-07 13:25:30.125", ParseResult::DateTime(at("2022-11-07 13:25:30.125"))),
+        ("in 500ms", ParseResult::DateTime(at("2010-01-01 12:00:00.5"))),
+        ("in 250 milliseconds", ParseResult::DateTime(at("2010-01-01 12:00:00.25"))),
+        ("200ms ago", ParseResult::DateTime(at("2010-01-01 11:59:59.8"))),
+        ("in 1 second 30 microseconds", ParseResult::DateTime(at("2010-01-01 12:00:01.00003"))),
+        ("3 µs ago", ParseResult::DateTime(at("2010-01-01 11:59:59.999997"))),
+        ("in 2 minutes", ParseResult::DateTime(at("2010-01-01 12:02:00"))),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(from_human_time(input, now).unwrap(), expected, "{input}");
+    }
+
+    let now = at("2010-01-01 12:00:00.123456789");
+    let result = from_human_time("end of this millisecond", now).unwrap();
+    assert_eq!(result, ParseResult::DateTime(at("2010-01-01 12:00:00.123999999")));
+}
+
+#[test]
+fn test_relative_long_periods() {
+    let now = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+
+    let cases = [
+        ("this decade", "2020-01-01"),
+        ("next decade", "2030-01-01"),
+        ("last decade", "2010-01-01"),
+        ("next century", "2100-01-01"),
+        ("last millennium", "1000-01-01"),
+        ("end of next decade", "2039-12-31"),
+        ("first year of last century", "1900-01-01"),
+        ("last day of this century", "2099-12-31"),
+    ];
+
+    for (input, expected) in cases {
+        let expected = NaiveDate::parse_from_str(expected, "%Y-%m-%d").unwrap();
+        assert_eq!(from_human_time(input, now).unwrap(), ParseResult::Date(expected), "{input}");
+    }
+
+    // Durations still count from now.
+    let result = from_human_time("in a decade", now).unwrap();
+    assert_eq!(result, ParseResult::DateTime(NaiveDate::from_ymd_opt(2034, 3, 1).unwrap().and_hms_opt(12, 0, 0).unwrap()));
+}
+
+#[test]
+fn test_time_fill() {
+    let at = |datetime: &str| NaiveDateTime::parse_from_str(datetime, "%Y-%m-%d %H:%M:%S%.f").unwrap();
+    let now = at("2010-01-01 12:00:00");
+    let nine = TimeFill::Fixed(NaiveTime::from_hms_opt(9, 0, 0).unwrap());
+
+    let cases = [
+        ("tomorrow", TimeFill::StartOfDay, "2010-01-02 00:00:00"),
+        ("tomorrow", TimeFill::EndOfDay, "2010-01-02 23:59:59.999999999"),
+        ("tomorrow", TimeFill::Now, "2010-01-02 12:00:00"),
+        ("tomorrow", nine, "2010-01-02 09:00:00"),
+        ("March 2024", TimeFill::EndOfDay, "2024-03-31 23:59:59.999999999"),
+        ("March 2024", nine, "2024-03-01 09:00:00"),
+        ("2024", TimeFill::EndOfDay, "2024-12-31 23:59:59.999999999"),
+        ("15:00", TimeFill::EndOfDay, "2010-01-01 15:00:00"),
+        ("2022-11-07 13:25:30", TimeFill::StartOfDay, "2022-11-07 13:25:30"),
+    ];
+
+    for (input, fill, expected) in cases {
+        let result = from_human_time(input, now).unwrap();
+        assert_eq!(result.to_datetime(now, fill), Some(at(expected)), "{input} with {fill:?}");
+    }
+
+    let config = crate::ParseConfig {
+        time_fill: nine,
+        ..Default::default()
+    };
+    let result = crate::from_human_time_with_config("12 hours ago at tomorrow", now, config).unwrap();
+    assert_eq!(result, ParseResult::DateTime(at("2010-01-01 21:00:00")));
+
+    let result = crate::from_human_time_with_config("tomorrow", now, config).unwrap();
+    assert_eq!(result.to_datetime_with_config(now, config), Some(at("2010-01-02 09:00:00")));
+    let outcome = crate::from_human_time_detailed("tomorrow", now, config).unwrap();
+    assert_eq!(outcome.datetime, Some(at("2010-01-02 09:00:00")));
+    let outcome = crate::from_human_time_detailed("15:30", now, config).unwrap();
+    assert_eq!(outcome.datetime, Some(at("2010-01-01 15:30:00")));
+}
+
+#[test]
+fn test_detailed_outcome() {
+    use crate::{Precision, Tense};
+
+    let now = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+        NaiveTime::from_hms_opt(12, 0, 0).unwrap()
+    );
+    let config = crate::ParseConfig {
+        holiday_calendar: Some(&crate::holidays::US),
+        ..Default::default()
+    };
+
+    let cases = [
+        ("2024", Precision::Year, false, Tense::Future, 0..4),
+        ("March 2009", Precision::Month, false, Tense::Past, 0..10),
+        ("2009-06-01", Precision::Day, false, Tense::Past, 0..10),
+        ("Christmas 2009", Precision::Day, false, Tense::Past, 0..14),
+        ("Christmas", Precision::Day, true, Tense::Future, 0..9),
+        ("today", Precision::Day, true, Tense::Present, 0..5),
+        ("this weekend", Precision::Day, true, Tense::Future, 0..12),
+        ("tomorrow at 10:00", Precision::Minute, true, Tense::Future, 0..17),
+        ("2009-06-01 10:00:30", Precision::Second, false, Tense::Past, 0..19),
+        ("3 hours ago", Precision::Hour, true, Tense::Past, 0..11),
+        ("in 2 days", Precision::Day, true, Tense::Future, 0..9),
+        ("now", Precision::Second, true, Tense::Present, 0..3),
+        ("15:00:30", Precision::Second, true, Tense::Future, 0..8),
+        ("15:00:30.125", Precision::Millisecond, true, Tense::Future, 0..12),
+        ("200ms ago", Precision::Millisecond, true, Tense::Past, 0..9),
+        ("in 5 microseconds", Precision::Microsecond, true, Tense::Future, 0..17),
+        ("next friday and then some", Precision::Day, true, Tense::Future, 0..11),
+        ("5 march 24", Precision::Day, true, Tense::Future, 0..10),
+        ("march 5 2024", Precision::Day, false, Tense::Future, 0..12),
+        ("Q3 24", Precision::Day, true, Tense::Future, 0..5),
+    ];
+
+    for (input, precision, relative, tense, span) in cases {
+        let outcome = crate::from_human_time_detailed(input, now, config).unwrap();
+        assert_eq!(outcome.value, crate::from_human_time_with_config(input, now, config).unwrap());
+        assert_eq!(outcome.precision, precision, "{input}");
+        assert_eq!(outcome.relative, relative, "{input}");
+        assert_eq!(outcome.tense, tense, "{input}");
+        assert_eq!(outcome.span, span, "{input}");
+    }
+
+    // Two-digit years only depend on `now` when they are expanded into a sliding window.
+    let fixed = crate::ParseConfig {
+        two_digit_years: crate::TwoDigitYears::Fixed { pivot: 70 },
+        ..config
+    };
+    assert!(!crate::from_human_time_detailed("5 march 24", now, fixed).unwrap().relative);
+}
+
+#[test]
+fn test_partial_precision_results() {
+    let now = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap()
+    );
+
+    let cases = [
+        ("March 2024", ParseResult::YearMonth(2024, chrono::Month::March)),
+        ("in march", ParseResult::YearMonth(2010, chrono::Month::March)),
+        ("last january", ParseResult::YearMonth(2009, chrono::Month::January)),
+        ("2024", ParseResult::Year(2024)),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(from_human_time(input, now).unwrap(), expected, "{input}");
+    }
+
+    assert_eq!(ParseResult::YearMonth(2024, chrono::Month::March).to_string(), "2024-03");
+    assert_eq!(ParseResult::Year(2024).to_string(), "2024");
+}
+
+#[test]
+fn test_range_evaluation_mode() {
+    let now = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap()
+    );
+
+    let range = crate::ParseConfig {
+        evaluation_mode: crate::EvaluationMode::Range,
+        ..Default::default()
+    };
+    let middle_east_range = crate::ParseConfig {
+        weekend: chrono::WeekdaySet::from_array([chrono::Weekday::Fri, chrono::Weekday::Sat]),
+        ..range
+    };
+
+    let cases = [
+        (range, "next weekend", "2010-01-09", "2010-01-10"),
+        (range, "the weekend after next", "2010-01-16", "2010-01-17"),
+        (middle_east_range, "this weekend", "2010-01-01", "2010-01-02"),
+    ];
+
+    for (config, input, expected_start, expected_end) in cases {
+        let result = crate::from_human_time_with_config(input, now, config).unwrap();
+        let expected_start = NaiveDate::parse_from_str(expected_start, "%Y-%m-%d").unwrap();
+        let expected_end = NaiveDate::parse_from_str(expected_end, "%Y-%m-%d").unwrap();
+
+        match result {
+            crate::ParseResult::DateRange(start, end) => {
+                assert_eq!((start, end), (expected_start, expected_end), "{input}")
+            }
+            _ => panic!("Expected DateRange result for {input}"),
+        }
+    }
+
+    let cases = [
+        ("mid-March", "2010-03-11", "2010-03-20"),
+        ("late 2024", "2024-09-01", "2024-12-31"),
+        ("early next week", "2010-01-03", "2010-01-04"),
+        ("late february", "2010-02-19", "2010-02-28"),
+        ("next April", "2010-04-01", "2010-04-30"),
+        ("2024", "2024-01-01", "2024-12-31"),
+    ];
+
+    for (input, expected_start, expected_end) in cases {
+        let result = crate::from_human_time_with_config(input, now, range).unwrap();
+        let expected_start = NaiveDate::parse_from_str(expected_start, "%Y-%m-%d").unwrap();
+        let expected_end = NaiveDate::parse_from_str(expected_end, "%Y-%m-%d").unwrap();
+
+        match result {
+            crate::ParseResult::DateRange(start, end) => {
+                assert_eq!((start, end), (expected_start, expected_end), "{input}")
+            }
+            _ => panic!("Expected DateRange result for {input}"),
+        }
+    }
+
+    // Single days are unaffected
+    let result = crate::from_human_time_with_config("tomorrow", now, range).unwrap();
+    assert!(matches!(result, crate::ParseResult::Date(_)));
+}
+
+#[test]
+fn test_business_hours_config() {
+    let config = crate::ParseConfig {
+        business_hours: crate::BusinessHours {
+            open: NaiveTime::from_hms_opt(8, 30, 0).unwrap(),
+            close: NaiveTime::from_hms_opt(16, 30, 0).unwrap(),
+        },
+        holiday_calendar: Some(&crate::holidays::US),
+        ..Default::default()
+    };
+
+    let cases = [
+        ("2009-12-24 15:00", "in 4 working hours", "2009-12-28 11:00"),
+        ("2009-12-28 10:00", "2 business hours ago", "2009-12-24 16:00"),
+        ("2009-12-28 07:00", "in 1 working hour", "2009-12-28 09:30"),
+        ("2009-12-28 20:00", "1 working hour ago", "2009-12-28 15:30"),
+        ("2009-12-24 12:00", "by close of business tomorrow", "2009-12-28 16:30"),
+        ("2009-12-24 12:00", "start of business", "2009-12-24 08:30"),
+    ];
+
+    for (now, input, expected) in cases {
+        let now = NaiveDateTime::parse_from_str(now, "%Y-%m-%d %H:%M").unwrap();
+        let expected = NaiveDateTime::parse_from_str(expected, "%Y-%m-%d %H:%M").unwrap();
+        let result = crate::from_human_time_with_config(input, now, config).unwrap();
+
+        match result {
+            crate::ParseResult::DateTime(datetime) => assert_eq!(datetime, expected, "{input}"),
+            _ => panic!("Expected DateTime result for {input}"),
+        }
+    }
+
+    let closed = crate::ParseConfig {
+        business_hours: crate::BusinessHours {
+            open: NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            close: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+        },
+        ..Default::default()
+    };
+    let now = NaiveDateTime::parse_from_str("2010-01-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    assert!(crate::from_human_time_with_config("in 2 business hours", now, closed).is_err());
+}
+
+#[test]
+fn test_sprints_config() {
+    let now = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap()
+    );
+
+    let config = crate::ParseConfig {
+        sprints: Some(crate::Sprints {
+            anchor: NaiveDate::from_ymd_opt(2009, 12, 28).unwrap(),
+            length_days: 14,
+        }),
+        ..Default::default()
+    };
+
+    let cases = [
+        ("end of this sprint", "2010-01-10"),
+        ("end of next sprint", "2010-01-24"),
+        ("start of last sprint", "2009-12-14"),
+        ("first day of the sprint", "2009-12-28"),
+        ("last business day of the sprint", "2010-01-08"),
+        ("second week of next sprint", "2010-01-18"),
+        ("next sprint", "2010-01-15"),
+        ("in 2 sprints", "2010-01-29"),
+        ("an iteration ago", "2009-12-18"),
+    ];
+
+    for (input, expected) in cases {
+        let result = crate::from_human_time_with_config(input, now, config).unwrap();
+        let expected = NaiveDate::parse_from_str(expected, "%Y-%m-%d").unwrap();
+
+        match result {
+            crate::ParseResult::Date(date) => assert_eq!(date, expected, "{input}"),
+            crate::ParseResult::DateTime(datetime) => assert_eq!(datetime.date(), expected, "{input}"),
+            _ => panic!("Expected Date result for {input}"),
+        }
+    }
+
+    assert!(crate::from_human_time("end of this sprint", now).is_err());
+    assert!(crate::from_human_time("in 2 sprints", now).is_err());
+}
+
+#[test]
+fn test_parts_of_short_sprints() {
+    let now = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap()
+    );
+    let sprint = |length_days| crate::ParseConfig {
+        sprints: Some(crate::Sprints {
+            anchor: NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+            length_days,
+        }),
+        evaluation_mode: crate::EvaluationMode::Range,
+        ..Default::default()
+    };
+
+    let cases = [
+        (1, "early this sprint", "2010-01-01", "2010-01-01"),
+        (1, "mid this sprint", "2010-01-01", "2010-01-01"),
+        (1, "late this sprint", "2010-01-01", "2010-01-01"),
+        (2, "early this sprint", "2010-01-01", "2010-01-01"),
+        (2, "mid this sprint", "2010-01-01", "2010-01-01"),
+        (2, "late this sprint", "2010-01-02", "2010-01-02"),
+    ];
+
+    for (length_days, input, expected_start, expected_end) in cases {
+        let result = crate::from_human_time_with_config(input, now, sprint(length_days)).unwrap();
+        let expected_start = NaiveDate::parse_from_str(expected_start, "%Y-%m-%d").unwrap();
+        let expected_end = NaiveDate::parse_from_str(expected_end, "%Y-%m-%d").unwrap();
+        assert_eq!(
+            result,
+            crate::ParseResult::DateRange(expected_start, expected_end),
+            "{input} in a {length_days} day sprint"
+        );
+    }
+}
+
+#[test]
+fn test_seasons_at_end_of_date_range() {
+    let now = NaiveDate::from_ymd_opt(NaiveDate::MAX.year(), 7, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    let summer_start = NaiveDate::from_ymd_opt(NaiveDate::MAX.year(), 6, 1).unwrap();
+
+    assert_eq!(from_human_time("this summer", now).unwrap(), ParseResult::Date(summer_start));
+
+    let errors = match from_human_time("next summer", now) {
+        Err(ParseError::ProccessingErrors(errors)) => errors,
+        result => panic!("{result:?}"),
+    };
+    assert!(
+        matches!(errors[..], [ProcessingError::InvalidDate { year, .. }] if year == NaiveDate::MAX.year() + 1),
+        "{errors:?}"
+    );
+}
+
+#[test]
+fn test_season_config() {
+    let now = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap()
+    );
+
+    let astronomical = crate::ParseConfig {
+        seasons: crate::SeasonBoundaries::Astronomical,
+        ..Default::default()
+    };
+    let southern = crate::ParseConfig {
+        hemisphere: crate::Hemisphere::Southern,
+        ..Default::default()
+    };
+
+    let cases = [
+        (astronomical, "spring 2024", "2024-03-20"),
+        (astronomical, "summer 2024", "2024-06-20"),
+        (astronomical, "autumn 2024", "2024-09-22"),
+        (astronomical, "winter 2024", "2024-12-21"),
+        (astronomical, "end of summer 2024", "2024-09-21"),
+        (astronomical, "this winter", "2009-12-21"),
+        (southern, "next summer", "2010-12-01"),
+        (southern, "this summer", "2009-12-01"),
+        (southern, "winter 2024", "2024-06-01"),
+        (southern, "end of spring", "2010-11-30"),
+    ];
+
+    for (config, input, expected) in cases {
+        let result = crate::from_human_time_with_config(input, now, config).unwrap();
+        let expected = NaiveDate::parse_from_str(expected, "%Y-%m-%d").unwrap();
+
+        match result {
+            crate::ParseResult::Date(date) => assert_eq!(date, expected, "{input}"),
+            _ => panic!("Expected Date result for {input}"),
+        }
+    }
+
+    let range = crate::ParseConfig {
+        evaluation_mode: crate::EvaluationMode::Range,
+        ..astronomical
+    };
+    let result = crate::from_human_time_with_config("winter 2023", now, range).unwrap();
+    let expected = (
+        NaiveDate::from_ymd_opt(2023, 12, 22).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 3, 19).unwrap(),
+    );
+    assert!(matches!(result, crate::ParseResult::DateRange(start, end) if (start, end) == expected));
+}
+
+#[test]
+fn test_easter_computus() {
+    let cases = [(1583, 4, 10), (1818, 3, 22), (1943, 4, 25), (2000, 4, 23), (2024, 3, 31), (2025, 4, 20)];
+
+    for (year, month, day) in cases {
+        assert_eq!(crate::holidays::easter_sunday(year), NaiveDate::from_ymd_opt(year, month, day));
+    }
+    assert_eq!(crate::holidays::easter_sunday(1582), None);
+}
+
 generate_test_cases_error!(
+    "6th week of the month",
+    "garbage",
     "2023-11-31",
     "31st day of february",
     "30th day of february",
-    "32nd day of january"
+    "32nd day of january",
+    "13th month of the year",
+    "54th week of the year",
+    "8th day of next week",
+    "6th week of february",
+    "25th hour of today",
+    "first month of next month",
+    "2nd week of next week",
+    "Q5",
+    "Q0 2024",
+    "FY25 Q7",
+    "fifth quarter of the year",
+    "first fiscal quarter of the year",
+    "2024-W42-8",
+    "2024-W42-0",
+    "2010-W53",
+    "week 0",
+    "week 53 of 2010",
+    "2023-366",
+    "2024-000",
+    "day 0",
+    "day 366",
+    "day 367 of 2024",
+    "christmas",
+    "early tomorrow",
+    "mid next hour",
+    "the 30th of next month",
+    "February 30 next year",
+    "the 32nd",
+    "the last",
+    "24:01",
+    "24:00:01",
+    "23:60:60",
+    "12:30:60",
+    "2500 hours",
+    "15h60",
+    "11th year of the decade",
+    "second millennium of the century"
 );